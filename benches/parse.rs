@@ -1,7 +1,7 @@
 use std::{hint::black_box, str::FromStr};
 
 use card_validate::Validate;
-use creditcard::CreditCard;
+use creditcard::{CreditCard, Issuer, IssuerCache};
 use criterion::{Criterion, criterion_group, criterion_main};
 
 fn too_short(c: &mut Criterion) {
@@ -60,5 +60,59 @@ fn valid(c: &mut Criterion) {
 	group.finish();
 }
 
-criterion_group!(benches, too_short, too_long, invalid, valid);
+/// Whether `card_validate` recognises the issuer, so the two can be compared.
+fn card_validate_supports(issuer: Issuer) -> bool {
+	matches!(
+		issuer,
+		Issuer::VisaElectron
+			| Issuer::Maestro
+			| Issuer::Dankort
+			| Issuer::Visa
+			| Issuer::Mir
+			| Issuer::Mastercard
+			| Issuer::AmericanExpress
+			| Issuer::DinersClub
+			| Issuer::Discover
+			| Issuer::UnionPay
+			| Issuer::Jcb
+	)
+}
+
+fn issuers(c: &mut Criterion) {
+	for &issuer in Issuer::ALL {
+		let card = issuer.example().pan().to_string();
+		let mut group = c.benchmark_group(format!("valid {}", issuer.name()));
+
+		group.bench_function("creditcard", |b| {
+			b.iter(|| CreditCard::from_str(black_box(&card)))
+		});
+
+		if card_validate_supports(issuer) {
+			group.bench_function("card_validate", |b| {
+				b.iter(|| Validate::from(black_box(&card)))
+			});
+		}
+
+		group.finish();
+	}
+}
+
+fn cached(c: &mut Criterion) {
+	let mut group = c.benchmark_group("repeated iin");
+	let mut cache: IssuerCache = IssuerCache::new();
+
+	group.bench_function("uncached", |b| {
+		b.iter(|| CreditCard::parse_str(black_box("6040010000000008")))
+	});
+
+	group.bench_function("cached", |b| {
+		b.iter(|| cache.parse_cached(black_box("6040010000000008")))
+	});
+
+	group.finish();
+}
+
+criterion_group!(
+	benches, too_short, too_long, invalid, valid, issuers, cached
+);
 criterion_main!(benches);
@@ -0,0 +1,20 @@
+#![no_main]
+
+use creditcard::CreditCard;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+	let from_bytes = CreditCard::from_bytes(data);
+
+	if let Ok(card) = std::str::from_utf8(data) {
+		let parsed = CreditCard::parse_str(card);
+
+		// both entry points agree on unseparated input
+		assert_eq!(parsed, from_bytes);
+
+		let _ = card.parse::<CreditCard>();
+		let _ = CreditCard::parse_str_canonical_spacing(card);
+		let _ = CreditCard::parse_partial(card);
+		let _ = CreditCard::parse_masked(card);
+	}
+});
@@ -0,0 +1,105 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{CreditCard, Error, Issuer};
+
+/// Parses every input, splitting the valid cards from the invalid inputs
+/// and their errors.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::Error;
+///
+/// let (valid, invalid) = creditcard::partition_valid(["4111111111111111", "1234"]);
+///
+/// assert_eq!(valid.len(), 1);
+/// assert_eq!(invalid, [("1234", Error::UnknownType)]);
+/// ```
+#[must_use]
+pub fn partition_valid<'a, I: IntoIterator<Item = &'a str>>(
+	inputs: I,
+) -> (Vec<CreditCard>, Vec<(&'a str, Error)>) {
+	let mut valid = Vec::new();
+	let mut invalid = Vec::new();
+
+	for input in inputs {
+		match CreditCard::parse_str(input) {
+			Ok(card) => valid.push(card),
+			Err(error) => invalid.push((input, error)),
+		}
+	}
+
+	(valid, invalid)
+}
+
+/// Counts the valid cards among `inputs` by issuer, ignoring invalid ones.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::Issuer;
+///
+/// let counts = creditcard::tally(["4111111111111111", "4012888888881881", "1234"]);
+///
+/// assert_eq!(counts[&Issuer::Visa], 2);
+/// assert_eq!(counts.len(), 1);
+/// ```
+#[must_use]
+pub fn tally<'a, I: IntoIterator<Item = &'a str>>(inputs: I) -> BTreeMap<Issuer, usize> {
+	let mut counts = BTreeMap::new();
+
+	for card in inputs
+		.into_iter()
+		.filter_map(|input| CreditCard::parse_str(input).ok())
+	{
+		*counts.entry(card.issuer).or_insert(0) += 1;
+	}
+
+	counts
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_partition_valid() {
+		let (valid, invalid) = partition_valid([
+			"4111111111111111",
+			"4111111111111112",
+			"378282246310005",
+			"not a card",
+			"41111111111111111",
+		]);
+
+		assert_eq!(valid, [
+			CreditCard::parse_str("4111111111111111").unwrap(),
+			CreditCard::parse_str("378282246310005").unwrap(),
+		]);
+		assert_eq!(invalid, [
+			("4111111111111112", Error::InvalidLuhn),
+			("not a card", Error::InvalidFormat),
+			("41111111111111111", Error::InvalidLength),
+		]);
+	}
+
+	#[test]
+	fn test_tally() {
+		let counts = tally([
+			"4111111111111111",
+			"378282246310005",
+			"4012888888881881",
+			"4111111111111112",
+			"5555555555554444",
+			"not a card",
+			"371449635398431",
+			"4222222222222",
+		]);
+
+		assert_eq!(counts.len(), 3);
+		assert_eq!(counts[&Issuer::Visa], 3);
+		assert_eq!(counts[&Issuer::AmericanExpress], 2);
+		assert_eq!(counts[&Issuer::Mastercard], 1);
+		assert!(tally([]).is_empty());
+	}
+}
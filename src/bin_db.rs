@@ -0,0 +1,85 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{CreditCard, iin};
+
+/// A user-supplied table of issuing banks by IIN.
+///
+/// No data is bundled, only the lookup. Ranges are inclusive 8-digit IINs
+/// and should not overlap.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{BinDatabase, CreditCard};
+///
+/// let db = BinDatabase::new([(41111100, 41111199, "Example Bank")]);
+/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+///
+/// assert_eq!(card.bank_name(&db), Some("Example Bank"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BinDatabase {
+	/// Sorted by the low end of each range.
+	ranges: Vec<(u32, u32, String)>,
+}
+
+impl BinDatabase {
+	/// Builds a database from `(low, high, bank_name)` entries.
+	#[must_use]
+	pub fn new<'a, I: IntoIterator<Item = (u32, u32, &'a str)>>(entries: I) -> Self {
+		let mut ranges = entries
+			.into_iter()
+			.map(|(low, high, name)| (low, high, String::from(name)))
+			.collect::<Vec<_>>();
+
+		ranges.sort_unstable_by_key(|&(low, ..)| low);
+
+		Self { ranges }
+	}
+
+	/// The bank whose range contains the 8-digit `iin`.
+	#[must_use]
+	pub fn lookup(&self, iin: u32) -> Option<&str> {
+		let index = self.ranges.partition_point(|&(low, ..)| low <= iin);
+		let (_, high, name) = self.ranges.get(index.checked_sub(1)?)?;
+
+		(iin <= *high).then_some(name.as_str())
+	}
+}
+
+impl CreditCard {
+	/// The issuing bank of the card, looked up by IIN in `db`.
+	#[must_use]
+	pub fn bank_name<'a>(&self, db: &'a BinDatabase) -> Option<&'a str> {
+		db.lookup(iin::iin8(self.pan, self.length()))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_bank_name() {
+		let db = BinDatabase::new([
+			(55555500, 55555599, "Mastercard Bank"),
+			(41111100, 41111199, "Visa Bank"),
+			(40128800, 40128899, "Other Visa Bank"),
+		]);
+
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.bank_name(&db), Some("Visa Bank"));
+
+		let card = "4012888888881881".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.bank_name(&db), Some("Other Visa Bank"));
+
+		let card = "378282246310005".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.bank_name(&db), None);
+		assert_eq!(db.lookup(41111200), None);
+		assert_eq!(db.lookup(40000000), None);
+		assert_eq!(BinDatabase::default().lookup(41111111), None);
+	}
+}
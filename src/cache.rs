@@ -0,0 +1,123 @@
+use crate::{CreditCard, Error, Issuer, iin};
+
+/// Marks an empty slot, since it is above every 8-digit IIN.
+const EMPTY: u32 = u32::MAX;
+
+/// A direct-mapped cache of the issuers of up to `N` recently seen IINs.
+///
+/// Useful for large datasets where the same IINs repeat, since only the
+/// length and Luhn checks run again for a cached IIN. Two IINs with the same
+/// remainder modulo `N` evict one another.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{CreditCard, IssuerCache};
+///
+/// let mut cache: IssuerCache = IssuerCache::new();
+///
+/// for card in ["4111111111111111", "4111111111111111", "378282246310005"] {
+///     assert_eq!(cache.parse_cached(card), CreditCard::parse_str(card));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct IssuerCache<const N: usize = 256> {
+	entries: [(u32, Option<Issuer>); N],
+}
+
+impl<const N: usize> IssuerCache<N> {
+	/// Creates an empty cache.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[must_use]
+	pub const fn new() -> Self {
+		assert!(N > 0, "cache must hold at least one entry");
+
+		Self {
+			entries: [(EMPTY, None); N],
+		}
+	}
+
+	/// Parse a credit card number from a string, as with
+	/// [`CreditCard::parse_str`], looking up its issuer in the cache first.
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_cached(&mut self, card: &str) -> Result<CreditCard, Error> {
+		let bytes = card.as_bytes();
+		let pan = CreditCard::validate_ascii(bytes)?;
+
+		// extended ranges need more than the 8 digits the cache is keyed on
+		let issuer = match iin::lookup_extended(iin::EXTENDED_IIN_RANGES, pan, bytes.len()) {
+			Some(issuer) => Some(issuer),
+			None => self.lookup(iin::iin8(pan, bytes.len())),
+		};
+
+		CreditCard::from_ascii_issuer(bytes, pan, issuer.ok_or(Error::UnknownType)?)
+	}
+
+	fn lookup(&mut self, iin: u32) -> Option<Issuer> {
+		let entry = &mut self.entries[iin as usize % N];
+
+		if entry.0 != iin {
+			*entry = (iin, iin::lookup(iin));
+		}
+
+		entry.1
+	}
+}
+
+impl<const N: usize> Default for IssuerCache<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::format;
+
+	use super::*;
+
+	#[test]
+	fn test_parse_cached() {
+		// small enough that IINs collide
+		let mut cache = IssuerCache::<3>::new();
+		let mut pan = 4111111111111111u64;
+
+		let mut cards = std::vec::Vec::new();
+
+		for _ in 0..500 {
+			cards.push(format!("{pan}"));
+			pan = pan
+				.wrapping_mul(6364136223846793005)
+				.wrapping_add(1442695040888963407)
+				% 10u64.pow(16);
+		}
+
+		for &issuer in Issuer::ALL {
+			cards.push(format!("{}", issuer.example().pan()));
+		}
+
+		for card in [
+			"",
+			"4111x",
+			"41111111111111111111",
+			"0411111111111111",
+			"411",
+		] {
+			cards.push(card.into());
+		}
+
+		// twice, so the second pass hits whatever survived in the cache
+		for _ in 0..2 {
+			for card in &cards {
+				assert_eq!(
+					cache.parse_cached(card),
+					CreditCard::parse_str(card),
+					"{card}"
+				);
+			}
+		}
+	}
+}
@@ -0,0 +1,107 @@
+use crate::{CreditCard, Error, Issuer, iin};
+
+/// A range of 8-digit IINs whose cards also carry a second network.
+struct CoBrand {
+	low: u32,
+	high: u32,
+	issuer: Issuer,
+}
+
+/// Known co-branding relationships, keyed on the primary issuer's IINs.
+///
+/// Unlike the IIN table, entries here may overlap ranges of other issuers,
+/// since the card is valid on both networks.
+///
+/// Only relationships with a published shared IIN range are listed. RuPay
+/// cards co-branded with Visa are issued from ordinary Visa BINs that are not
+/// published as a range, and Elo and Carte Bancaire have no [`Issuer`]
+/// variant, so none of these are detected.
+#[allow(clippy::inconsistent_digit_grouping)]
+const CO_BRANDS: &[CoBrand] = &[
+	// RuPay global cards issued on the Discover network
+	CoBrand {
+		low: 6521_0000,
+		high: 6522_9999,
+		issuer: Issuer::RuPay,
+	},
+	// RuPay global cards issued on the JCB network
+	CoBrand {
+		low: 353_00000,
+		high: 353_99999,
+		issuer: Issuer::RuPay,
+	},
+	CoBrand {
+		low: 356_00000,
+		high: 356_99999,
+		issuer: Issuer::RuPay,
+	},
+	// Visa/Dankort
+	CoBrand {
+		low: 4571_0000,
+		high: 4571_9999,
+		issuer: Issuer::Dankort,
+	},
+];
+
+/// Parses a card number, returning its issuer followed by any network it is
+/// known to be co-branded with.
+///
+/// Only RuPay on the Discover and JCB networks, and Visa/Dankort, are known.
+/// RuPay/Visa cards share no published range, so they are reported as
+/// [`Issuer::Visa`] alone.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::Issuer;
+///
+/// let brands = creditcard::co_brands("6521000000000007").unwrap();
+///
+/// assert_eq!(brands, [Issuer::Discover, Issuer::RuPay]);
+/// ```
+#[must_use = "the card is only validated if the result is checked"]
+pub fn co_brands(card: &str) -> Result<heapless::Vec<Issuer, 4>, Error> {
+	let card = CreditCard::parse_str(card)?;
+	let iin = iin::iin8(card.pan, card.length());
+
+	let mut brands = heapless::Vec::new();
+	// capacity is at least 1
+	let _ = brands.push(card.issuer);
+
+	for co_brand in CO_BRANDS {
+		if co_brand.low > iin || iin > co_brand.high || brands.contains(&co_brand.issuer) {
+			continue;
+		}
+
+		if brands.push(co_brand.issuer).is_err() {
+			break;
+		}
+	}
+
+	Ok(brands)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_co_brands() {
+		assert_eq!(co_brands("6521000000000007").unwrap(), [
+			Issuer::Discover,
+			Issuer::RuPay
+		]);
+		assert_eq!(co_brands("3561000000000005").unwrap(), [
+			Issuer::Jcb,
+			Issuer::RuPay
+		]);
+		assert_eq!(co_brands("4571000000000001").unwrap(), [
+			Issuer::Visa,
+			Issuer::Dankort
+		]);
+		assert_eq!(co_brands("4111111111111111").unwrap(), [Issuer::Visa]);
+		// RuPay/Visa cards cannot be told apart from other Visa cards
+		assert_eq!(co_brands("4000056655665556").unwrap(), [Issuer::Visa]);
+		assert_eq!(co_brands("1234"), Err(Error::UnknownType));
+	}
+}
@@ -0,0 +1,61 @@
+use crate::{CreditCard, Error};
+
+/// Parses each comma-separated card number in `line`, with surrounding
+/// spaces trimmed.
+///
+/// Empty segments are skipped. Once either list holds `N` entries, further
+/// results of that kind are dropped.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::Error;
+///
+/// let (cards, errors) = creditcard::parse_csv_line::<4>("4111111111111111, 1234");
+///
+/// assert_eq!(cards[0].pan(), 4111111111111111);
+/// assert_eq!(errors, [Error::UnknownType]);
+/// ```
+#[must_use]
+pub fn parse_csv_line<const N: usize>(
+	line: &str,
+) -> (heapless::Vec<CreditCard, N>, heapless::Vec<Error, N>) {
+	let mut cards = heapless::Vec::new();
+	let mut errors = heapless::Vec::new();
+
+	for segment in line.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+		// full lists drop the rest
+		let _ = match CreditCard::parse_str(segment) {
+			Ok(card) => cards.push(card).map_err(|_| ()),
+			Err(error) => errors.push(error).map_err(|_| ()),
+		};
+	}
+
+	(cards, errors)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_csv_line() {
+		let (cards, errors) =
+			parse_csv_line::<4>("4111111111111111, 5555555555554444 ,4111111111111112,378282246310005");
+
+		assert_eq!(cards.len(), 3);
+		assert_eq!(cards[0].pan(), 4111111111111111);
+		assert_eq!(cards[1].pan(), 5555555555554444);
+		assert_eq!(cards[2].pan(), 378282246310005);
+		assert_eq!(errors, [Error::InvalidLuhn]);
+
+		let (cards, errors) = parse_csv_line::<1>("4111111111111111,,5555555555554444, x, y");
+
+		assert_eq!(cards.len(), 1);
+		assert_eq!(errors, [Error::InvalidFormat]);
+
+		let (cards, errors) = parse_csv_line::<1>("");
+
+		assert!(cards.is_empty() && errors.is_empty());
+	}
+}
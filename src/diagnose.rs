@@ -0,0 +1,136 @@
+use crate::{Error, ascii_to_pan, iin, is_ascii_digits, luhn};
+
+/// Runs every check on a card number, returning all of the errors found
+/// rather than only the first.
+///
+/// An empty result means the card is valid. An empty number only reports
+/// [`Error::Empty`], and one that is not made up of digits only
+/// [`Error::InvalidFormat`], since no other check can run.
+///
+/// Errors are reported in the order parsing runs its checks, so the first is
+/// the one [`CreditCard::parse_str`](crate::CreditCard::parse_str) returns.
+/// Like parsing, numbers shorter than 12 digits or with a leading zero are
+/// not classified, and so report [`Error::UnknownType`].
+///
+/// # Example
+///
+/// ```
+/// use creditcard::Error;
+///
+/// // a Visa number that is both too long and fails the Luhn check
+/// let errors = creditcard::diagnose("41111111111111112");
+///
+/// assert_eq!(errors, [Error::InvalidLength, Error::InvalidLuhn]);
+/// ```
+#[must_use]
+pub fn diagnose(card: &str) -> heapless::Vec<Error, 4> {
+	let bytes = card.as_bytes();
+	let mut errors = heapless::Vec::new();
+
+	// capacity is at least 1
+	if bytes.is_empty() {
+		let _ = errors.push(Error::Empty);
+
+		return errors;
+	}
+
+	if !is_ascii_digits(bytes) {
+		let _ = errors.push(Error::InvalidFormat);
+
+		return errors;
+	}
+
+	// only the leading digits matter for the issuer, and 19 always fit
+	let len = bytes.len().min(19);
+	let issuer = match bytes[0] {
+		b'0' => None,
+		_ if len < 12 => None,
+		_ => iin::lookup_with(iin::EXTENDED_IIN_RANGES, ascii_to_pan(&bytes[..len]), len),
+	};
+
+	let length_valid = match issuer {
+		Some(issuer) => issuer.is_length_valid(bytes.len()),
+		None => (12..=19).contains(&bytes.len()),
+	};
+
+	// at most 3 of the 4 slots are used below, and parsing rejects numbers
+	// that are too long before classifying them
+	if bytes.len() > 19 {
+		let _ = errors.push(Error::InvalidLength);
+	}
+
+	if issuer.is_none() {
+		let _ = errors.push(Error::UnknownType);
+	}
+
+	if !length_valid && bytes.len() <= 19 {
+		let _ = errors.push(Error::InvalidLength);
+	}
+
+	if issuer.is_none_or(|issuer| issuer.requires_luhn()) && !luhn::is_valid(bytes) {
+		let _ = errors.push(Error::InvalidLuhn);
+	}
+
+	errors
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::CreditCard;
+
+	#[test]
+	fn test_diagnose() {
+		assert_eq!(diagnose("4111111111111111"), []);
+		assert_eq!(diagnose("37828224631000"), [
+			Error::InvalidLength,
+			Error::InvalidLuhn
+		]);
+		assert_eq!(diagnose("4111111111111112"), [Error::InvalidLuhn]);
+		assert_eq!(diagnose("411111111111116"), [Error::InvalidLength]);
+		assert_eq!(diagnose("7111111111111112"), [
+			Error::UnknownType,
+			Error::InvalidLuhn
+		]);
+		assert_eq!(diagnose("41111111111111111111111"), [
+			Error::InvalidLength,
+			Error::InvalidLuhn
+		]);
+		assert_eq!(diagnose("41111"), [
+			Error::UnknownType,
+			Error::InvalidLength
+		]);
+		assert_eq!(diagnose("71111111111111111111"), [
+			Error::InvalidLength,
+			Error::UnknownType,
+			Error::InvalidLuhn
+		]);
+		assert_eq!(diagnose("4111-1111"), [Error::InvalidFormat]);
+		assert_eq!(diagnose(""), [Error::Empty]);
+	}
+
+	#[test]
+	fn test_diagnose_agrees_with_parse() {
+		for card in [
+			"4111111111111111",
+			"4111111111111112",
+			"378282246310005",
+			"37828224631000",
+			"7111111111111111",
+			"41111111111111111111",
+			"71111111111111111111",
+			"41111",
+			"4111111111111",
+			"378282",
+			"0411111111111111",
+			"4",
+		] {
+			let errors = diagnose(card);
+
+			match CreditCard::parse_str(card) {
+				Ok(_) => assert!(errors.is_empty()),
+				Err(error) => assert_eq!(errors.first(), Some(&error)),
+			}
+		}
+	}
+}
@@ -0,0 +1,97 @@
+use crate::CreditCard;
+
+impl CreditCard {
+	/// A stable fingerprint of the card number, keyed by the 128-bit `key`.
+	///
+	/// The same card and key always produce the same value, so this can be
+	/// used to count unique cards without storing their numbers.
+	///
+	/// The fingerprint is SipHash-2-4 of the card number, a keyed
+	/// pseudorandom function: without the key, fingerprints cannot be
+	/// linked to card numbers. The space of valid card numbers is small
+	/// enough that anyone holding the key can brute-force it, so keep the key
+	/// secret and generate it randomly.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.fingerprint(42), card.fingerprint(42));
+	/// ```
+	#[must_use]
+	pub fn fingerprint(&self, key: u128) -> u64 {
+		siphash24(key, self.pan)
+	}
+}
+
+/// SipHash-2-4 of the little-endian bytes of `message`.
+fn siphash24(key: u128, message: u64) -> u64 {
+	let k0 = key as u64;
+	let k1 = (key >> 64) as u64;
+	let mut v = [
+		k0 ^ 0x736f6d6570736575,
+		k1 ^ 0x646f72616e646f6d,
+		k0 ^ 0x6c7967656e657261,
+		k1 ^ 0x7465646279746573,
+	];
+
+	// the message is always one full block, followed by its length
+	for block in [message, 8 << 56] {
+		v[3] ^= block;
+		sip_round(&mut v);
+		sip_round(&mut v);
+		v[0] ^= block;
+	}
+
+	v[2] ^= 0xff;
+
+	for _ in 0..4 {
+		sip_round(&mut v);
+	}
+
+	v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+fn sip_round(v: &mut [u64; 4]) {
+	v[0] = v[0].wrapping_add(v[1]);
+	v[1] = v[1].rotate_left(13) ^ v[0];
+	v[0] = v[0].rotate_left(32);
+	v[2] = v[2].wrapping_add(v[3]);
+	v[3] = v[3].rotate_left(16) ^ v[2];
+	v[0] = v[0].wrapping_add(v[3]);
+	v[3] = v[3].rotate_left(21) ^ v[0];
+	v[2] = v[2].wrapping_add(v[1]);
+	v[1] = v[1].rotate_left(17) ^ v[2];
+	v[2] = v[2].rotate_left(32);
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_fingerprint() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+		let same = "4111111111111111".parse::<CreditCard>().unwrap();
+		let other = "5555555555554444".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.fingerprint(1), same.fingerprint(1));
+		assert_ne!(card.fingerprint(1), card.fingerprint(2));
+		assert_ne!(card.fingerprint(1), other.fingerprint(1));
+	}
+
+	#[test]
+	fn test_siphash24() {
+		// reference vector for the 8-byte message 00..07 under the key 00..0f
+		let key = u128::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+		assert_eq!(siphash24(key, 0x0706050403020100), 0x93f5f5799a932462);
+
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.fingerprint(key), 0x0217964f7904368d);
+	}
+}
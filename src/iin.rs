@@ -0,0 +1,949 @@
+use core::fmt;
+
+use crate::{CreditCard, Error, Issuer, IssuerSet, luhn, pan_to_ascii};
+
+/// An inclusive range of 8-digit IINs belonging to an issuer.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IinRange {
+	pub low: u32,
+	pub high: u32,
+	/// How many leading digits the range is defined over.
+	pub digits: u8,
+	pub issuer: Issuer,
+}
+
+/// An inclusive range of IINs that need more than 8 digits to identify.
+///
+/// `low` and `high` are the first `digits` digits of the card number.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ExtendedIinRange {
+	pub digits: u32,
+	pub low: u64,
+	pub high: u64,
+	pub issuer: Issuer,
+}
+
+/// Ranges that are only distinguishable past the 8th digit. These take
+/// precedence over [`IIN_RANGES`].
+///
+/// Kept separate so the common case stays a single 8-digit match.
+pub(crate) const EXTENDED_IIN_RANGES: &[ExtendedIinRange] = &[];
+
+/// Generates both [`lookup`] and [`IIN_RANGES`] from the same arms, so the
+/// table can never drift from the match used on the parsing fast path.
+///
+/// Arms are grouped by how many leading digits they are defined over, and
+/// are matched in order, so earlier arms take precedence.
+macro_rules! iin_ranges {
+	($($digits:literal => {
+		$($($low:literal..=$high:literal)|+ => $issuer:path),* $(,)?
+	})*) => {
+		/// Maps an 8-digit IIN to its issuer.
+		pub(crate) const fn lookup(iin: u32) -> Option<Issuer> {
+			#[allow(clippy::inconsistent_digit_grouping)]
+			let issuer = match iin {
+				$($($($low..=$high)|+ => $issuer,)*)*
+				_ => return None,
+			};
+
+			Some(issuer)
+		}
+
+		/// Every IIN range, in order of precedence.
+		#[allow(clippy::inconsistent_digit_grouping)]
+		pub(crate) const IIN_RANGES: &[IinRange] = &[
+			$($($(IinRange {
+				low: $low,
+				high: $high,
+				digits: $digits,
+				issuer: $issuer,
+			},)+)*)*
+		];
+	};
+}
+
+// check in increase order of IIN length
+iin_ranges! {
+	8 => {
+		60400100_..=60420099_ => Issuer::UkrCard,
+	}
+	6 => {
+		506099_00..=506198_99 | 650002_00..=650027_99 | 507865_00..=507964_99 => Issuer::Verve,
+		622126_00..=622925_99 => Issuer::Discover,
+		417500_00..=417500_99 => Issuer::VisaElectron,
+		357111_00..=357111_99 => Issuer::LankaPay,
+		676770_00..=676770_99 | 676774_00..=676774_99 => Issuer::MaestroUk,
+		564182_00..=564182_99 | 633110_00..=633110_99 => Issuer::Switch,
+	}
+	4 => {
+		6011_0000..=6011_9999 => Issuer::Discover,
+		3528_0000..=3589_9999 => Issuer::Jcb,
+		6759_0000..=6759_9999 => Issuer::MaestroUk,
+		5018_0000..=5018_9999
+		| 5020_0000..=5020_9999
+		| 5038_0000..=5038_9999
+		| 5893_0000..=5893_9999
+		| 6304_0000..=6304_9999
+		| 6761_0000..=6763_9999 => Issuer::Maestro,
+		5019_0000..=5019_9999 => Issuer::Dankort,
+		2200_0000..=2204_9999 => Issuer::Mir,
+		2205_0000..=2205_9999 => Issuer::Borica,
+		2221_0000..=2720_9999 => Issuer::Mastercard,
+		9792_0000..=9792_9999 => Issuer::Troy,
+		4026_0000..=4026_9999
+		| 4508_0000..=4508_9999
+		| 4844_0000..=4844_9999
+		| 4913_0000..=4913_9999
+		| 4917_0000..=4917_9999 => Issuer::VisaElectron,
+		1946_0000..=1946_9999 => Issuer::Gpn,
+		4903_0000..=4903_9999
+		| 4905_0000..=4905_9999
+		| 4911_0000..=4911_9999
+		| 4936_0000..=4936_9999
+		| 6333_0000..=6333_9999 => Issuer::Switch,
+		6334_0000..=6334_9999 | 6767_0000..=6767_9999 => Issuer::Solo,
+		6706_0000..=6706_9999 | 6709_0000..=6709_9999 | 6771_0000..=6771_9999 => Issuer::Laser,
+		6541_0000..=6541_9999 | 6556_0000..=6556_9999 => Issuer::BcCard,
+		2014_0000..=2014_9999 | 2149_0000..=2149_9999 => Issuer::EnRoute,
+	}
+	3 => {
+		644_00000..=649_99999 => Issuer::Discover,
+		508_00000..=508_99999 => Issuer::RuPay,
+		636_00000..=636_99999 => Issuer::InterPayment,
+		637_00000..=639_99999 => Issuer::InstaPayment,
+	}
+	2 => {
+		34_000000..=34_999999 | 37_000000..=37_999999 => Issuer::AmericanExpress,
+		31_000000..=31_999999 => Issuer::ChinaTUnion,
+		62_000000..=62_999999 => Issuer::UnionPay,
+		30_000000..=30_999999
+		| 36_000000..=36_999999
+		| 38_000000..=38_999999
+		| 39_000000..=39_999999 => Issuer::DinersClub,
+		65_000000..=65_999999 => Issuer::Discover,
+		60_000000..=60_999999 | 81_000000..=81_999999 | 82_000000..=82_999999 => Issuer::RuPay,
+		51_000000..=55_999999 => Issuer::Mastercard,
+		50_000000..=50_999999
+		| 56_000000..=56_999999
+		| 58_000000..=58_999999
+		| 60_000000..=63_999999 => Issuer::Gpn,
+	}
+	1 => {
+		4_0000000..=4_9999999 => Issuer::Visa,
+		1_0000000..=1_9999999 => Issuer::Uatp,
+	}
+}
+
+// catches swapped or mistyped bounds at compile time
+const _: () = {
+	let mut i = 0;
+
+	while i < IIN_RANGES.len() {
+		let range = &IIN_RANGES[i];
+		let scale = 10u32.pow(8 - range.digits as u32);
+
+		assert!(range.low <= range.high, "IIN range bounds are swapped");
+		assert!(range.high <= 99_999_999, "IIN range is not 8 digits");
+		assert!(
+			range.low.is_multiple_of(scale) && (range.high + 1).is_multiple_of(scale),
+			"IIN range does not match its number of digits"
+		);
+
+		i += 1;
+	}
+
+	let mut i = 0;
+
+	while i < EXTENDED_IIN_RANGES.len() {
+		let range = &EXTENDED_IIN_RANGES[i];
+
+		assert!(range.low <= range.high, "IIN range bounds are swapped");
+		assert!(
+			range.digits > 8 && range.digits <= 19,
+			"extended IIN range is not 9 to 19 digits"
+		);
+		assert!(
+			range.high < 10u64.pow(range.digits),
+			"IIN range does not match its number of digits"
+		);
+
+		i += 1;
+	}
+};
+
+/// Maps a card number of `len` digits to its issuer, consulting `extended`
+/// before the 8-digit ranges.
+pub(crate) const fn lookup_with(
+	extended: &[ExtendedIinRange],
+	pan: u64,
+	len: usize,
+) -> Option<Issuer> {
+	match lookup_extended(extended, pan, len) {
+		Some(issuer) => Some(issuer),
+		None => lookup(iin8(pan, len)),
+	}
+}
+
+/// The first 8 digits of a card number of `len` digits, zero-extended if
+/// there are fewer.
+pub(crate) const fn iin8(pan: u64, len: usize) -> u32 {
+	let len = len as u32;
+
+	if len >= 8 {
+		(pan / 10u64.pow(len - 8)) as u32
+	} else {
+		pan as u32 * 10u32.pow(8 - len)
+	}
+}
+
+/// Maps a card number of `len` digits to the first range in `extended` it
+/// falls in.
+pub(crate) const fn lookup_extended(
+	extended: &[ExtendedIinRange],
+	pan: u64,
+	len: usize,
+) -> Option<Issuer> {
+	let len = len as u32;
+	let mut i = 0;
+
+	while i < extended.len() {
+		let range = &extended[i];
+
+		if len >= range.digits {
+			let prefix = pan / 10u64.pow(len - range.digits);
+
+			if range.low <= prefix && prefix <= range.high {
+				return Some(range.issuer);
+			}
+		}
+
+		i += 1;
+	}
+
+	None
+}
+
+/// How many other issuers have ranges carved out of the range `iin` is
+/// classified by.
+pub(crate) fn sharing_issuers(iin: u32) -> usize {
+	let Some(index) = IIN_RANGES
+		.iter()
+		.position(|range| range.low <= iin && iin <= range.high)
+	else {
+		return 0;
+	};
+
+	let matched = &IIN_RANGES[index];
+	let mut seen = IssuerSet::new();
+	let mut count = 0;
+
+	// only earlier ranges take precedence, and none of them contain `iin`
+	for range in &IIN_RANGES[..index] {
+		let overlaps = range.low <= matched.high && matched.low <= range.high;
+
+		if overlaps && range.issuer != matched.issuer && !seen.contains(range.issuer) {
+			seen.insert(range.issuer);
+			count += 1;
+		}
+	}
+
+	count
+}
+
+/// What an incomplete IIN can still resolve to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Prefix {
+	/// Every IIN starting with the prefix belongs to at most this issuer.
+	Unique(Issuer),
+	/// IINs starting with the prefix belong to different issuers.
+	Ambiguous,
+	/// No IIN starting with the prefix belongs to any issuer.
+	Unknown,
+}
+
+/// Classifies the first `digits` digits of an IIN.
+///
+/// `digits` must be at most 8.
+pub(crate) fn lookup_prefix(prefix: u32, digits: usize) -> Prefix {
+	let scale = 10u32.pow(8 - digits as u32);
+	let low = prefix * scale;
+	let high = low + (scale - 1);
+
+	let mut found = None;
+
+	for range in IIN_RANGES {
+		if range.high < low || range.low > high {
+			continue;
+		}
+
+		// nothing before this range overlaps, so it shadows the rest
+		if found.is_none() && range.low <= low && range.high >= high {
+			return Prefix::Unique(range.issuer);
+		}
+
+		match found {
+			Some(issuer) if issuer != range.issuer => return Prefix::Ambiguous,
+			_ => found = Some(range.issuer),
+		}
+	}
+
+	found.map_or(Prefix::Unknown, Prefix::Unique)
+}
+
+/// Classifies the leading ascii `digits` of a card number that may have more
+/// digits to come.
+///
+/// Returns [`Error::InvalidLength`] if the digits belong to an issuer that
+/// allows no length of at least `digits.len()`.
+pub(crate) fn classify_prefix(digits: &[u8]) -> Result<Prefix, Error> {
+	if digits.first() == Some(&b'0') {
+		return Ok(Prefix::Unknown);
+	}
+
+	let prefix_len = digits.len().min(8);
+	let prefix = digits[..prefix_len]
+		.iter()
+		.fold(0, |prefix, &c| prefix * 10 + u32::from(c - b'0'));
+
+	match lookup_prefix(prefix, prefix_len) {
+		// too many digits for every length this issuer allows
+		Prefix::Unique(issuer) if !(digits.len()..=19).any(|len| issuer.is_length_valid(len)) => {
+			Err(Error::InvalidLength)
+		}
+		prefix => Ok(prefix),
+	}
+}
+
+impl Issuer {
+	/// The numerically smallest valid card number of this issuer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::Visa.min_pan().pan(), 4000000000006);
+	/// ```
+	#[must_use]
+	pub fn min_pan(self) -> CreditCard {
+		let len = (12..=19).find(|&len| self.is_length_valid(len)).unwrap();
+		let iin = self.iins().map(|(low, _)| low).min().unwrap();
+
+		self.complete(u64::from(iin) * 10u64.pow(len as u32 - 9), 0)
+	}
+
+	/// The numerically largest valid card number of this issuer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::Visa.max_pan().pan(), 4999999999999999993);
+	/// ```
+	#[must_use]
+	pub fn max_pan(self) -> CreditCard {
+		let len = (12..=19)
+			.rev()
+			.find(|&len| self.is_length_valid(len))
+			.unwrap();
+		let iin = self.iins().map(|(_, high)| high).max().unwrap();
+		let scale = 10u64.pow(len as u32 - 9);
+
+		self.complete(u64::from(iin) * scale + (scale - 1), 9)
+	}
+
+	/// The lowest and highest 8-digit IIN of each of this issuer's ranges
+	/// that is actually classified as this issuer, skipping any part of the
+	/// range taken by an earlier one.
+	fn iins(self) -> impl Iterator<Item = (u32, u32)> {
+		// the range an IIN is classified by
+		let winner = |iin: u32| {
+			IIN_RANGES
+				.iter()
+				.find(|range| range.low <= iin && iin <= range.high)
+				.unwrap()
+		};
+
+		IIN_RANGES
+			.iter()
+			.filter(move |range| range.issuer == self)
+			.filter_map(move |range| {
+				let mut low = range.low;
+				let mut high = range.high;
+
+				while low <= range.high && winner(low).issuer != self {
+					low = winner(low).high + 1;
+				}
+
+				while high >= range.low && winner(high).issuer != self {
+					high = winner(high).low.checked_sub(1)?;
+				}
+
+				(low <= high).then_some((low, high))
+			})
+	}
+
+	/// Appends a check digit to `payload`, or `unchecked` if this issuer does
+	/// not use one.
+	fn complete(self, payload: u64, unchecked: u8) -> CreditCard {
+		let check_digit = if self.requires_luhn() {
+			luhn::check_digit(pan_to_ascii(payload, &mut [0; 20]))
+		} else {
+			unchecked
+		};
+
+		CreditCard {
+			pan: payload * 10 + u64::from(check_digit),
+			issuer: self,
+		}
+	}
+}
+
+/// The IIN of a card, formatted zero-padded to its width.
+///
+/// Formats as decimal with [`Display`](fmt::Display) and as hexadecimal
+/// with [`LowerHex`](fmt::LowerHex).
+///
+/// # Example
+///
+/// ```
+/// use creditcard::CreditCard;
+///
+/// let card = "378282246310005".parse::<CreditCard>().unwrap();
+/// let iin = card.iin();
+///
+/// assert_eq!(iin.to_string(), "378282");
+/// assert_eq!(format!("{iin:x}"), "05c5aa");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IinDisplay {
+	iin: u32,
+	width: usize,
+}
+
+impl IinDisplay {
+	/// The IIN as an integer.
+	#[must_use]
+	pub fn value(self) -> u32 {
+		self.iin
+	}
+
+	/// The number of digits in the IIN, either 6 or 8.
+	#[must_use]
+	pub fn width(self) -> usize {
+		self.width
+	}
+}
+
+impl fmt::Display for IinDisplay {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:01$}", self.iin, self.width)
+	}
+}
+
+impl fmt::LowerHex for IinDisplay {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:01$x}", self.iin, self.width)
+	}
+}
+
+impl CreditCard {
+	/// The IIN of the card, 8 digits for cards of 16 or more digits and 6
+	/// digits otherwise, as per ISO/IEC 7812-1:2017.
+	#[must_use]
+	pub fn iin(&self) -> IinDisplay {
+		let width = if self.length() >= 16 { 8 } else { 6 };
+
+		IinDisplay {
+			iin: (self.pan / 10u64.pow((self.length() - width) as u32)) as u32,
+			width,
+		}
+	}
+
+	/// The first six digits of the card, the classic BIN still used by
+	/// many lookup services.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4012888888881881".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.bin6(), 401288);
+	/// ```
+	#[must_use]
+	pub fn bin6(&self) -> u32 {
+		(self.pan / 10u64.pow(self.length() as u32 - 6)) as u32
+	}
+
+	/// How many leading digits of this card decided its issuer.
+	///
+	/// Unlike [`Issuer::iin_significant_digits`], this depends on the number
+	/// itself, since an issuer's ranges can be defined over different
+	/// lengths. Cards classified through an [`IssuerTable`](crate::IssuerTable)
+	/// override report 8.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let visa = "4111111111111111".parse::<CreditCard>().unwrap();
+	/// let electron = "4175001111111117".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(visa.decisive_prefix_len(), 1);
+	/// assert_eq!(electron.decisive_prefix_len(), 6);
+	/// ```
+	#[must_use]
+	pub fn decisive_prefix_len(&self) -> u8 {
+		let len = self.length();
+
+		if let Some(range) = EXTENDED_IIN_RANGES.iter().find(|range| {
+			let Some(rest) = (len as u32).checked_sub(range.digits) else {
+				return false;
+			};
+			let prefix = self.pan / 10u64.pow(rest);
+
+			range.low <= prefix && prefix <= range.high
+		}) {
+			return range.digits as u8;
+		}
+
+		let iin = iin8(self.pan, len);
+
+		IIN_RANGES
+			.iter()
+			.find(|range| range.low <= iin && iin <= range.high)
+			.filter(|range| range.issuer == self.issuer)
+			.map_or(8, |range| range.digits)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_decisive_prefix_len() {
+		for (card, digits) in [
+			("4111111111111111", 1),
+			("4175001111111117", 6),
+			("4026111111111115", 4),
+			("5555555555554444", 2),
+			("2223000048400011", 4),
+			("378282246310005", 2),
+			("6011000990139424", 4),
+			("6221260000000000", 6),
+			("6200000000000005", 2),
+		] {
+			let card = card.parse::<CreditCard>().unwrap();
+
+			assert_eq!(card.decisive_prefix_len(), digits, "{card:?}");
+		}
+
+		let table: crate::IssuerTable =
+			crate::IssuerTable::new().with_override(41111111, 41111111, Issuer::UnionPay);
+		let card = CreditCard::parse_str_with("4111111111111111", &table).unwrap();
+
+		assert_eq!(card.decisive_prefix_len(), 8);
+	}
+
+	/// The IIN ranges documented on each [`Issuer`] variant, in the order of
+	/// [`Issuer::ALL`]. Keep in sync with the `IIN ranges` doc lines.
+	const DOCUMENTED_RANGES: &[(Issuer, &[(u32, u32)])] = &[
+		(Issuer::AmericanExpress, &[(34, 34), (37, 37)]),
+		(Issuer::ChinaTUnion, &[(31, 31)]),
+		(Issuer::UnionPay, &[(62, 62)]),
+		(Issuer::DinersClub, &[
+			(30, 30),
+			(36, 36),
+			(38, 38),
+			(39, 39),
+		]),
+		(Issuer::Discover, &[
+			(6011, 6011),
+			(644, 649),
+			(65, 65),
+			(622126, 622925),
+		]),
+		(Issuer::UkrCard, &[(60400100, 60420099)]),
+		(Issuer::RuPay, &[
+			(60, 60),
+			(65, 65),
+			(81, 81),
+			(82, 82),
+			(508, 508),
+			(353, 353),
+			(356, 356),
+		]),
+		(Issuer::InterPayment, &[(636, 636)]),
+		(Issuer::InstaPayment, &[(637, 639)]),
+		(Issuer::Jcb, &[(3528, 3589)]),
+		(Issuer::MaestroUk, &[
+			(6759, 6759),
+			(676770, 676770),
+			(676774, 676774),
+		]),
+		(Issuer::Maestro, &[
+			(5018, 5018),
+			(5020, 5020),
+			(5038, 5038),
+			(5893, 5893),
+			(6304, 6304),
+			(6761, 6761),
+			(6762, 6762),
+			(6763, 6763),
+		]),
+		(Issuer::Dankort, &[(5019, 5019)]),
+		(Issuer::Mir, &[(2200, 2204)]),
+		(Issuer::Borica, &[(2205, 2205)]),
+		(Issuer::Mastercard, &[(2221, 2720), (51, 55)]),
+		(Issuer::Troy, &[(65, 65), (9792, 9792)]),
+		(Issuer::Visa, &[(4, 4)]),
+		(Issuer::VisaElectron, &[
+			(4026, 4026),
+			(417500, 417500),
+			(4508, 4508),
+			(4844, 4844),
+			(4913, 4913),
+			(4917, 4917),
+		]),
+		(Issuer::Uatp, &[(1, 1)]),
+		(Issuer::Verve, &[
+			(506099, 506198),
+			(650002, 650027),
+			(507865, 507964),
+		]),
+		(Issuer::LankaPay, &[(357111, 357111)]),
+		(Issuer::Gpn, &[
+			(1946, 1946),
+			(50, 50),
+			(56, 56),
+			(58, 58),
+			(60, 63),
+		]),
+		(Issuer::Switch, &[
+			(4903, 4903),
+			(4905, 4905),
+			(4911, 4911),
+			(4936, 4936),
+			(564182, 564182),
+			(633110, 633110),
+			(6333, 6333),
+			(6759, 6759),
+		]),
+		(Issuer::Solo, &[(6334, 6334), (6767, 6767)]),
+		(Issuer::Laser, &[
+			(6304, 6304),
+			(6706, 6706),
+			(6709, 6709),
+			(6771, 6771),
+		]),
+		(Issuer::BcCard, &[(6541, 6541), (6556, 6556)]),
+		(Issuer::EnRoute, &[(2014, 2014), (2149, 2149)]),
+	];
+
+	/// Parts of an issuer's documented ranges that are classified as another
+	/// issuer, which takes precedence, along with that issuer.
+	///
+	/// A carve-out of the winning issuer applies within one of these too, so
+	/// RuPay's 65 range resolves to Verve wherever Discover's does.
+	const CARVE_OUTS: &[(Issuer, (u32, u32), Issuer)] = &[
+		(Issuer::UnionPay, (622126, 622925), Issuer::Discover),
+		(Issuer::Discover, (650002, 650027), Issuer::Verve),
+		(Issuer::Discover, (6541, 6541), Issuer::BcCard),
+		(Issuer::Discover, (6556, 6556), Issuer::BcCard),
+		(Issuer::RuPay, (6011, 6011), Issuer::Discover),
+		(Issuer::RuPay, (60400100, 60420099), Issuer::UkrCard),
+		(Issuer::RuPay, (65, 65), Issuer::Discover),
+		(Issuer::RuPay, (353, 353), Issuer::Jcb),
+		(Issuer::RuPay, (356, 356), Issuer::Jcb),
+		(Issuer::Jcb, (357111, 357111), Issuer::LankaPay),
+		(Issuer::Troy, (65, 65), Issuer::Discover),
+		(Issuer::Visa, (4026, 4026), Issuer::VisaElectron),
+		(Issuer::Visa, (417500, 417500), Issuer::VisaElectron),
+		(Issuer::Visa, (4508, 4508), Issuer::VisaElectron),
+		(Issuer::Visa, (4844, 4844), Issuer::VisaElectron),
+		(Issuer::Visa, (4903, 4903), Issuer::Switch),
+		(Issuer::Visa, (4905, 4905), Issuer::Switch),
+		(Issuer::Visa, (4911, 4911), Issuer::Switch),
+		(Issuer::Visa, (4913, 4913), Issuer::VisaElectron),
+		(Issuer::Visa, (4917, 4917), Issuer::VisaElectron),
+		(Issuer::Visa, (4936, 4936), Issuer::Switch),
+		(Issuer::Uatp, (1946, 1946), Issuer::Gpn),
+		(Issuer::Gpn, (5018, 5018), Issuer::Maestro),
+		(Issuer::Gpn, (5019, 5019), Issuer::Dankort),
+		(Issuer::Gpn, (5020, 5020), Issuer::Maestro),
+		(Issuer::Gpn, (5038, 5038), Issuer::Maestro),
+		(Issuer::Gpn, (506099, 506198), Issuer::Verve),
+		(Issuer::Gpn, (507865, 507964), Issuer::Verve),
+		(Issuer::Gpn, (508, 508), Issuer::RuPay),
+		(Issuer::Gpn, (564182, 564182), Issuer::Switch),
+		(Issuer::Gpn, (5893, 5893), Issuer::Maestro),
+		(Issuer::Gpn, (60, 60), Issuer::RuPay),
+		(Issuer::Gpn, (62, 62), Issuer::UnionPay),
+		(Issuer::Gpn, (6304, 6304), Issuer::Maestro),
+		(Issuer::Gpn, (633110, 633110), Issuer::Switch),
+		(Issuer::Gpn, (6333, 6333), Issuer::Switch),
+		(Issuer::Gpn, (6334, 6334), Issuer::Solo),
+		(Issuer::Gpn, (636, 636), Issuer::InterPayment),
+		(Issuer::Gpn, (637, 639), Issuer::InstaPayment),
+		(Issuer::Switch, (6759, 6759), Issuer::MaestroUk),
+		(Issuer::Solo, (676770, 676770), Issuer::MaestroUk),
+		(Issuer::Solo, (676774, 676774), Issuer::MaestroUk),
+		(Issuer::Laser, (6304, 6304), Issuer::Maestro),
+	];
+
+	/// Overlapping arms of the same precedence, where the earlier one wins.
+	const OVERLAPPING: &[(Issuer, Issuer)] = &[
+		(Issuer::UnionPay, Issuer::Gpn),
+		(Issuer::RuPay, Issuer::Gpn),
+	];
+
+	/// Checks the IIN table against itself and against the ranges documented
+	/// on each [`Issuer`] variant.
+	fn verify_ranges() {
+		// arms defined over the same number of digits should not overlap,
+		// since which one wins then depends on their order alone
+		for (i, a) in IIN_RANGES.iter().enumerate() {
+			for b in &IIN_RANGES[i + 1..] {
+				let overlaps = a.low <= b.high && b.low <= a.high;
+
+				if overlaps && a.issuer != b.issuer && a.digits == b.digits {
+					assert!(
+						OVERLAPPING.contains(&(a.issuer, b.issuer)),
+						"{:?} {}..={} overlaps {:?} {}..={}",
+						a.issuer,
+						a.low,
+						a.high,
+						b.issuer,
+						b.low,
+						b.high
+					);
+				}
+			}
+		}
+
+		assert!(
+			DOCUMENTED_RANGES
+				.iter()
+				.map(|&(issuer, _)| issuer)
+				.eq(Issuer::ALL.iter().copied())
+		);
+
+		for &(owner, range, _) in CARVE_OUTS {
+			assert!(
+				documented(owner).any(|documented| contains(documented, range)),
+				"{owner:?} carve-out {range:?} is outside its documented ranges"
+			);
+		}
+
+		for &(issuer, ranges) in DOCUMENTED_RANGES {
+			for &range in ranges {
+				let (low, high) = widen(range);
+
+				// the classification can only change at the edge of an arm, so
+				// checking on both sides of each one covers the whole range
+				let mut iins = std::vec![low, high];
+
+				for iin in IIN_RANGES
+					.iter()
+					.map(|arm| (arm.low, arm.high))
+					.chain(CARVE_OUTS.iter().map(|&(.., range, _)| widen(range)))
+					.flat_map(|(low, high)| [low.saturating_sub(1), low, high, high + 1])
+				{
+					if (low..=high).contains(&iin) {
+						iins.push(iin);
+					}
+				}
+
+				for iin in iins {
+					let expected = expected_issuer(issuer, iin);
+
+					assert_eq!(lookup(iin), Some(expected), "{issuer:?} {range:?} at {iin}");
+					assert!(
+						expected != issuer || issuer.prefix_matches(iin),
+						"{issuer:?} documents {range:?} but no arm covers {iin}"
+					);
+				}
+			}
+		}
+	}
+
+	/// Widens documented prefixes to the 8-digit IINs they cover.
+	fn widen((low, high): (u32, u32)) -> (u32, u32) {
+		let scale = 10u32.pow(7 - low.ilog10());
+
+		(low * scale, high * scale + (scale - 1))
+	}
+
+	fn contains(outer: (u32, u32), inner: (u32, u32)) -> bool {
+		let (outer, inner) = (widen(outer), widen(inner));
+
+		outer.0 <= inner.0 && inner.1 <= outer.1
+	}
+
+	fn documented(issuer: Issuer) -> impl Iterator<Item = (u32, u32)> {
+		DOCUMENTED_RANGES
+			.iter()
+			.filter(move |&&(documented, _)| documented == issuer)
+			.flat_map(|&(_, ranges)| ranges.iter().copied())
+	}
+
+	/// The issuer `iin` should resolve to, given that it lies in a range
+	/// documented for `issuer`.
+	fn expected_issuer(mut issuer: Issuer, iin: u32) -> Issuer {
+		while let Some(&(.., winner)) = CARVE_OUTS.iter().find(|&&(owner, range, _)| {
+			let (low, high) = widen(range);
+
+			owner == issuer && (low..=high).contains(&iin)
+		}) {
+			issuer = winner;
+		}
+
+		issuer
+	}
+
+	#[test]
+	fn test_verify_ranges() {
+		verify_ranges();
+	}
+
+	#[test]
+	fn test_lookup_extended() {
+		// hypothetical range carved out of Visa at the 10th digit
+		let extended = [ExtendedIinRange {
+			digits: 10,
+			low: 4111111112,
+			high: 4111111113,
+			issuer: Issuer::Mastercard,
+		}];
+
+		assert_eq!(
+			lookup_with(&extended, 4111111112000000, 16),
+			Some(Issuer::Mastercard)
+		);
+		assert_eq!(
+			lookup_with(&extended, 4111111113999999, 16),
+			Some(Issuer::Mastercard)
+		);
+		assert_eq!(
+			lookup_with(&extended, 4111111111111111, 16),
+			Some(Issuer::Visa)
+		);
+		assert_eq!(
+			lookup_with(&extended, 4111111114000000, 16),
+			Some(Issuer::Visa)
+		);
+		assert_eq!(lookup_with(&extended, 411111111, 9), Some(Issuer::Visa));
+		assert_eq!(
+			lookup_with(EXTENDED_IIN_RANGES, 4111111112000000, 16),
+			Some(Issuer::Visa)
+		);
+	}
+
+	#[test]
+	fn test_iin_display() {
+		use std::format;
+
+		let card = "378282246310005".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.iin().value(), 378282);
+		assert_eq!(format!("{}", card.iin()), "378282");
+		assert_eq!(format!("{:x}", card.iin()), "05c5aa");
+
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.iin().width(), 8);
+		assert_eq!(format!("{}", card.iin()), "41111111");
+		assert_eq!(format!("{:x}", card.iin()), "02734e47");
+	}
+
+	#[test]
+	fn test_min_max_pan() {
+		for &issuer in Issuer::ALL {
+			for card in [issuer.min_pan(), issuer.max_pan()] {
+				let mut buf = [0; 20];
+				let digits = pan_to_ascii(card.pan(), &mut buf);
+
+				assert_eq!(CreditCard::from_bytes(digits), Ok(card), "{issuer:?}");
+				assert_eq!(issuer.accepts_length(card.length()), Ok(()));
+			}
+
+			// nothing smaller or larger with the same length is valid
+			let (min, max) = (issuer.min_pan(), issuer.max_pan());
+
+			assert_ne!(
+				CreditCard::reclassify(min.pan() - 10).ok(),
+				Some(issuer),
+				"{issuer:?}"
+			);
+			assert_ne!(
+				CreditCard::reclassify(max.pan() + 10).ok(),
+				Some(issuer),
+				"{issuer:?}"
+			);
+		}
+
+		assert_eq!(Issuer::AmericanExpress.min_pan().pan(), 340000000000009);
+		assert_eq!(Issuer::AmericanExpress.max_pan().pan(), 379999999999994);
+		assert_eq!(Issuer::Gpn.min_pan().pan(), 1946000000000005);
+		// 636 belongs to InterPayment
+		assert_eq!(Issuer::Gpn.max_pan().pan(), 6359999999999999998);
+	}
+
+	#[test]
+	fn test_bin6() {
+		let card = "4012888888881881".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.bin6(), 401288);
+		assert_eq!(card.iin().value(), 40128888);
+
+		let card = "378282246310005".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.bin6(), 378282);
+	}
+
+	#[test]
+	fn test_iin8() {
+		assert_eq!(iin8(4111111111111111, 16), 41111111);
+		assert_eq!(iin8(41111111111, 11), 41111111);
+		assert_eq!(iin8(41111111, 8), 41111111);
+		assert_eq!(iin8(3782, 4), 37820000);
+		assert_eq!(lookup_with(&[], 41111111111, 11), Some(Issuer::Visa));
+		assert_eq!(lookup_with(&[], 3782, 4), Some(Issuer::AmericanExpress));
+	}
+
+	#[test]
+	fn test_lookup_prefix() {
+		assert_eq!(lookup_prefix(0, 0), Prefix::Ambiguous);
+		assert_eq!(lookup_prefix(4, 1), Prefix::Ambiguous);
+		assert_eq!(lookup_prefix(41, 2), Prefix::Ambiguous);
+		assert_eq!(lookup_prefix(411, 3), Prefix::Unique(Issuer::Visa));
+		assert_eq!(
+			lookup_prefix(37, 2),
+			Prefix::Unique(Issuer::AmericanExpress)
+		);
+		assert_eq!(lookup_prefix(7, 1), Prefix::Unknown);
+		assert_eq!(lookup_prefix(4175, 4), Prefix::Ambiguous);
+		assert_eq!(
+			lookup_prefix(417500, 6),
+			Prefix::Unique(Issuer::VisaElectron)
+		);
+		assert_eq!(lookup_prefix(41111111, 8), Prefix::Unique(Issuer::Visa));
+	}
+
+	#[test]
+	fn test_classify_prefix() {
+		assert_eq!(classify_prefix(b"411"), Ok(Prefix::Unique(Issuer::Visa)));
+		assert_eq!(classify_prefix(b"41"), Ok(Prefix::Ambiguous));
+		assert_eq!(classify_prefix(b"0411"), Ok(Prefix::Unknown));
+		assert_eq!(classify_prefix(b"7"), Ok(Prefix::Unknown));
+		assert_eq!(
+			classify_prefix(b"3782822463100051"),
+			Err(Error::InvalidLength)
+		);
+	}
+}
@@ -0,0 +1,83 @@
+use crate::{ISSUER_COUNT, Issuer};
+
+/// A set of issuers, stored as a bitset.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{Issuer, IssuerSet};
+///
+/// let set = IssuerSet::from_iter([Issuer::Visa, Issuer::Mastercard]);
+///
+/// assert!(set.contains(Issuer::Visa));
+/// assert!(!set.contains(Issuer::AmericanExpress));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IssuerSet(u32);
+
+// every issuer needs its own bit; widen the set before adding a 33rd
+const _: () = assert!(ISSUER_COUNT <= u32::BITS as usize);
+
+impl IssuerSet {
+	/// Creates an empty set.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(0)
+	}
+
+	/// Adds an issuer to the set.
+	pub fn insert(&mut self, issuer: Issuer) {
+		self.0 |= Self::bit(issuer);
+	}
+
+	/// Removes an issuer from the set.
+	pub fn remove(&mut self, issuer: Issuer) {
+		self.0 &= !Self::bit(issuer);
+	}
+
+	/// Whether the issuer is in the set.
+	#[must_use]
+	pub fn contains(&self, issuer: Issuer) -> bool {
+		self.0 & Self::bit(issuer) != 0
+	}
+
+	fn bit(issuer: Issuer) -> u32 {
+		1 << issuer as u32
+	}
+}
+
+impl FromIterator<Issuer> for IssuerSet {
+	fn from_iter<I: IntoIterator<Item = Issuer>>(issuers: I) -> Self {
+		let mut set = Self::new();
+
+		for issuer in issuers {
+			set.insert(issuer);
+		}
+
+		set
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_issuer_set() {
+		let mut set = IssuerSet::new();
+
+		assert!(!set.contains(Issuer::Visa));
+
+		set.insert(Issuer::Visa);
+		set.insert(Issuer::BcCard);
+
+		assert!(set.contains(Issuer::Visa));
+		assert!(set.contains(Issuer::BcCard));
+		assert!(!set.contains(Issuer::VisaElectron));
+
+		set.remove(Issuer::Visa);
+
+		assert!(!set.contains(Issuer::Visa));
+		assert!(set.contains(Issuer::BcCard));
+	}
+}
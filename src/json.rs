@@ -0,0 +1,100 @@
+use core::fmt::{self, Write};
+
+use crate::{CreditCard, Error};
+
+/// Writes into a fixed buffer, failing once it is full.
+struct Cursor<'a> {
+	buf: &'a mut [u8],
+	len: usize,
+}
+
+impl Write for Cursor<'_> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+		let dest = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+
+		dest.copy_from_slice(s.as_bytes());
+		self.len = end;
+
+		Ok(())
+	}
+}
+
+/// Parses a card number and writes a JSON summary of it into `buf`, without
+/// the full number.
+///
+/// A valid card is written as
+/// `{"issuer":"Visa","last_four":"1111","valid":true}`, and an invalid one as
+/// `{"valid":false,"error":"invalid luhn checksum"}`. Returns
+/// [`Error::BufferTooSmall`] if `buf` cannot hold the JSON.
+///
+/// # Example
+///
+/// ```
+/// let mut buf = [0; 64];
+///
+/// assert_eq!(
+///     creditcard::parse_to_json("4111111111111111", &mut buf),
+///     Ok(r#"{"issuer":"Visa","last_four":"1111","valid":true}"#)
+/// );
+/// ```
+#[must_use = "the card is only validated if the result is checked"]
+pub fn parse_to_json<'a>(card: &str, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+	let mut cursor = Cursor { buf, len: 0 };
+
+	let result = match CreditCard::parse_str(card) {
+		Ok(card) => {
+			let card = card.mask();
+
+			write!(
+				cursor,
+				r#"{{"issuer":"{}","last_four":"{:04}","valid":true}}"#,
+				card.issuer().name(),
+				card.last_four()
+			)
+		}
+		Err(error) => write!(cursor, r#"{{"valid":false,"error":"{}"}}"#, error.as_str()),
+	};
+
+	result.map_err(|_| Error::BufferTooSmall)?;
+
+	let Cursor { buf, len } = cursor;
+
+	// only ascii was written
+	Ok(core::str::from_utf8(&buf[..len]).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_to_json() {
+		let mut buf = [0; 64];
+
+		assert_eq!(
+			parse_to_json("6200000000000005", &mut buf),
+			Ok(r#"{"issuer":"UnionPay","last_four":"0005","valid":true}"#)
+		);
+		assert_eq!(
+			parse_to_json("4111111111111112", &mut buf),
+			Ok(r#"{"valid":false,"error":"invalid luhn checksum"}"#)
+		);
+		assert_eq!(
+			parse_to_json("4111111111111111", &mut [0; 16]),
+			Err(Error::BufferTooSmall)
+		);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_parse_to_json_is_valid_json() {
+		let mut buf = [0; 64];
+		let json = parse_to_json("378282246310005", &mut buf).unwrap();
+		let value = serde_json::from_str::<serde_json::Value>(json).unwrap();
+
+		assert_eq!(value["issuer"], "American Express");
+		assert_eq!(value["last_four"], "0005");
+		assert_eq!(value["valid"], true);
+	}
+}
@@ -1,10 +1,72 @@
 //! Ported from https://github.com/valeriansaliou/rs-card-validate/blob/master/src/lib.rs
 
 #![no_std]
+#![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "alloc")]
+mod bin_db;
+mod cache;
+#[cfg(feature = "heapless")]
+mod co_brand;
+#[cfg(feature = "heapless")]
+mod csv;
+#[cfg(feature = "heapless")]
+mod diagnose;
+#[cfg(feature = "fingerprint")]
+mod fingerprint;
+mod iin;
+mod issuer_set;
+#[cfg(feature = "json")]
+mod json;
+mod lines;
 mod luhn;
-
-use core::str::FromStr;
+mod masked;
+mod normalize;
+mod pan;
+mod parser;
+mod parts;
+#[cfg(feature = "serde")]
+mod safe;
+#[cfg(feature = "alloc")]
+mod scan;
+mod table;
+mod token;
+
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "alloc")]
+pub use batch::{partition_valid, tally};
+#[cfg(feature = "alloc")]
+pub use bin_db::BinDatabase;
+pub use cache::IssuerCache;
+#[cfg(feature = "heapless")]
+pub use co_brand::co_brands;
+#[cfg(feature = "heapless")]
+pub use csv::parse_csv_line;
+#[cfg(feature = "heapless")]
+pub use diagnose::diagnose;
+pub use iin::IinDisplay;
+pub use issuer_set::IssuerSet;
+#[cfg(feature = "json")]
+pub use json::parse_to_json;
+pub use lines::validate_lines;
+pub use luhn::luhn_mod_n_is_valid;
+pub use masked::MaskedCard;
+pub use pan::Pan;
+pub use parser::{Parser, PushResult};
+pub use parts::CardParts;
+#[cfg(feature = "serde")]
+pub use safe::SafeCard;
+#[cfg(feature = "alloc")]
+pub use scan::scan_text;
+pub use table::IssuerTable;
 
 /// Common credit card issuers.
 ///
@@ -12,79 +74,216 @@ use core::str::FromStr;
 /// Since more may be added in the future, this has been marked
 /// `#[non_exhaustive]`.
 ///
+/// Each issuer is represented as a `u16` with the discriminant shown in its
+/// declaration. These values are part of the stable API, so they can be
+/// passed across an FFI boundary; new issuers only ever take new values.
+///
 /// Taken from [Wikipedia](https://en.wikipedia.org/wiki/Payment_card_number).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
+#[repr(u16)]
 pub enum Issuer {
 	/// IIN ranges: 34, 37
 	/// Length: 15
-	AmericanExpress,
+	AmericanExpress = 0,
 	/// IIN ranges: 31
 	/// Length: 19
-	ChinaTUnion,
+	ChinaTUnion = 1,
 	/// IIN ranges: 62
 	/// Length: 16-19
-	UnionPay,
+	///
+	/// Some UnionPay cards do not follow the Luhn algorithm. With the
+	/// `unionpay-no-luhn` feature, the checksum is not verified for them.
+	UnionPay = 2,
 	/// IIN ranges: 30, 36, 38, 39
 	/// Length: 14-19
-	DinersClub,
+	///
+	/// Includes the historical Carte Blanche range 300-305, which is now
+	/// issued as Diners Club.
+	DinersClub = 3,
 	/// IIN ranges: 6011, 644-649, 65, 622126-622925
 	/// Length: 16-19
-	Discover,
+	///
+	/// The 65 range excludes 650002-650027, which resolve to
+	/// [`Issuer::Verve`], and 6541 and 6556, which resolve to
+	/// [`Issuer::BcCard`]. The 622126-622925 range takes precedence over
+	/// [`Issuer::UnionPay`].
+	///
+	/// The 640-643 gap between InstaPayment and Discover is unassigned, so
+	/// such numbers are [`Error::UnknownType`].
+	Discover = 4,
 	/// IIN ranges: 60400100–60420099
 	/// Length: 16-19
-	UkrCard,
+	UkrCard = 5,
 	/// IIN ranges: 60, 65, 81, 82, 508, 353, 356
 	/// Length: 16
-	RuPay,
+	///
+	/// RuPay cards in the 65 range are classified as [`Issuer::Discover`],
+	/// and those in 353 and 356 as [`Issuer::Jcb`].
+	RuPay = 6,
 	/// IIN ranges: 636
 	/// Length: 16-19
-	InterPayment,
+	InterPayment = 7,
 	/// IIN ranges: 637-639
 	/// Length: 16
-	InstaPayment,
+	InstaPayment = 8,
 	/// IIN ranges: 3528–3589
 	/// Length: 16-19
-	Jcb,
+	Jcb = 9,
 	/// IIN ranges: 6759, 676770, 676774
 	/// Length: 12-19
-	MaestroUk,
+	MaestroUk = 10,
 	/// IIN ranges: 5018, 5020, 5038, 5893, 6304, 6761, 6762, 6763
 	/// Length: 12-19
-	Maestro,
+	Maestro = 11,
 	/// IIN ranges: 5019
 	/// Length: 16
-	Dankort,
+	Dankort = 12,
 	/// IIN ranges: 2200-2204
 	/// Length: 16-19
-	Mir,
+	Mir = 13,
 	/// IIN ranges: 2205
 	/// Length: 16
-	Borica,
+	Borica = 14,
 	/// IIN ranges: 2221–2720, 51-55
 	/// Length: 16
-	Mastercard,
+	Mastercard = 15,
 	/// IIN ranges: 65, 9792
 	/// Length: 16
-	Troy,
+	///
+	/// Troy cards in the 65 range are classified as [`Issuer::Discover`].
+	Troy = 16,
 	/// IIN ranges: 4
 	/// Length: 13, 16, 19
-	Visa,
+	///
+	/// Cards in the 4903, 4905, 4911 and 4936 ranges are classified as
+	/// [`Issuer::Switch`], and those in the Visa Electron ranges as
+	/// [`Issuer::VisaElectron`], even at lengths only Visa allows.
+	Visa = 17,
 	/// IIN ranges: 4026, 417500, 4508, 4844, 4913, 4917
 	/// Length: 16
-	VisaElectron,
+	///
+	/// These ranges take precedence over [`Issuer::Visa`], so a 417500 card
+	/// is Visa Electron while its 417499 and 417501 neighbours are Visa.
+	VisaElectron = 18,
 	/// IIN ranges: 1
 	/// Length: 15
-	Uatp,
+	Uatp = 19,
 	/// IIN ranges: 506099–506198, 650002–650027, 507865–507964
 	/// Length: 16, 18, 19
-	Verve,
+	Verve = 20,
 	/// IIN ranges: 357111
 	/// Length: 16
-	LankaPay,
+	LankaPay = 21,
 	/// IIN ranges: 1946, 50, 56, 58, 60-63
 	/// Length: 16, 18, 19
-	Gpn,
+	///
+	/// The narrower Maestro, Dankort, RuPay and Verve ranges within 50 and
+	/// 58 take precedence, so only the rest of them is GPN.
+	Gpn = 22,
+	/// IIN ranges: 4903, 4905, 4911, 4936, 564182, 633110, 6333, 6759
+	/// Length: 16, 18, 19
+	///
+	/// Legacy UK debit network, later rebranded as Maestro UK. The shared
+	/// 6759 range is classified as [`Issuer::MaestroUk`].
+	Switch = 23,
+	/// IIN ranges: 6334, 6767
+	/// Length: 16, 18, 19
+	///
+	/// Legacy UK debit network, discontinued in 2011. The 676770 and 676774
+	/// ranges are classified as [`Issuer::MaestroUk`].
+	Solo = 24,
+	/// IIN ranges: 6304, 6706, 6709, 6771
+	/// Length: 16-19
+	///
+	/// Irish debit network, discontinued in 2014. The shared 6304 range is
+	/// classified as [`Issuer::Maestro`].
+	Laser = 25,
+	/// IIN ranges: 6541, 6556
+	/// Length: 16
+	///
+	/// Korean BC Card (BC Global), carved out of the Discover 65 range.
+	BcCard = 26,
+	/// IIN ranges: 2014, 2149
+	/// Length: 15
+	///
+	/// Historical Diners Club enRoute cards, which do not use the Luhn
+	/// algorithm.
+	EnRoute = 27,
+}
+
+/// The number of issuers, for arrays indexed by [`Issuer::index`].
+///
+/// Since this grows whenever an issuer is added, arrays sized by a literal
+/// instead fail to compile rather than silently missing the new issuer.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{ISSUER_COUNT, Issuer};
+///
+/// let mut counts = [0; ISSUER_COUNT];
+///
+/// counts[Issuer::Visa.index()] += 1;
+/// ```
+pub const ISSUER_COUNT: usize = Issuer::ALL.len();
+
+/// The longest [`Issuer::name_len`] of any issuer, for aligning columns of
+/// issuer names.
+pub const MAX_ISSUER_NAME_LEN: usize = {
+	let mut max = 0;
+	let mut i = 0;
+
+	while i < Issuer::ALL.len() {
+		let len = Issuer::ALL[i].name_len();
+
+		if len > max {
+			max = len;
+		}
+
+		i += 1;
+	}
+
+	max
+};
+
+/// Which of its two BIN series a Mastercard belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MastercardSeries {
+	/// IIN ranges: 2221–2720
+	Two,
+	/// IIN ranges: 51-55
+	Five,
+}
+
+/// The card number lengths an issuer allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthKind {
+	/// Every length from the first to the second, inclusive.
+	Range(usize, usize),
+	/// Only these lengths, in increasing order.
+	Discrete(&'static [usize]),
+}
+
+/// How likely a card's issuer is to be right, given how many other issuers
+/// have ranges carved out of the one it matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+	/// Many issuers share the range, such as Discover's 65.
+	Low,
+	/// One other issuer shares the range.
+	Medium,
+	/// No other issuer shares the range, or the card is in a range carved
+	/// out of a broader one.
+	High,
+}
+
+/// The issuer of a card number, if it is recognised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+	Known(Issuer),
+	Unknown,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -93,10 +292,229 @@ pub enum Error {
 	UnknownType,
 	InvalidLength,
 	InvalidLuhn,
+	TestCard,
+	IssuerNotAllowed,
+	BufferTooSmall,
+	SuspiciousPattern,
+	Empty,
+}
+
+impl Error {
+	/// A short, static description of the error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Error;
+	///
+	/// assert_eq!(Error::InvalidLuhn.as_str(), "invalid luhn checksum");
+	/// ```
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Error::InvalidFormat => "invalid card number format",
+			Error::UnknownType => "unknown card type",
+			Error::InvalidLength => "invalid card number length",
+			Error::InvalidLuhn => "invalid luhn checksum",
+			Error::TestCard => "known test card number",
+			Error::IssuerNotAllowed => "card issuer not allowed",
+			Error::BufferTooSmall => "buffer too small",
+			Error::SuspiciousPattern => "suspicious card number pattern",
+			Error::Empty => "empty card number",
+		}
+	}
+
+	/// A stable, machine-readable identifier for the error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Error;
+	///
+	/// assert_eq!(Error::InvalidLuhn.code(), "invalid_luhn");
+	/// ```
+	#[must_use]
+	pub const fn code(self) -> &'static str {
+		match self {
+			Error::InvalidFormat => "invalid_format",
+			Error::UnknownType => "unknown_type",
+			Error::InvalidLength => "invalid_length",
+			Error::InvalidLuhn => "invalid_luhn",
+			Error::TestCard => "test_card",
+			Error::IssuerNotAllowed => "issuer_not_allowed",
+			Error::BufferTooSmall => "buffer_too_small",
+			Error::SuspiciousPattern => "suspicious_pattern",
+			Error::Empty => "empty",
+		}
+	}
+
+	/// A suggested HTTP status code for an API responding with this error.
+	///
+	/// Problems with the submitted card are 422 Unprocessable Content, while
+	/// [`Error::BufferTooSmall`] is a bug in the caller and so 500 Internal
+	/// Server Error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Error;
+	///
+	/// assert_eq!(Error::InvalidLuhn.http_status(), 422);
+	/// ```
+	#[must_use]
+	pub const fn http_status(self) -> u16 {
+		match self {
+			Error::BufferTooSmall => 500,
+			_ => 422,
+		}
+	}
+
+	/// Whether the error is likely a typo the user can fix by re-entering
+	/// the number, as opposed to a card that is not supported.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Error;
+	///
+	/// assert!(Error::InvalidLuhn.is_user_correctable());
+	/// assert!(!Error::UnknownType.is_user_correctable());
+	/// ```
+	#[must_use]
+	pub const fn is_user_correctable(self) -> bool {
+		matches!(
+			self,
+			Error::InvalidFormat | Error::InvalidLength | Error::InvalidLuhn | Error::Empty
+		)
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
 }
 
+/// Common names for issuers other than [`Issuer::name`], in lowercase.
+const NAME_ALIASES: &[(&str, Issuer)] = &[
+	("amex", Issuer::AmericanExpress),
+	("mc", Issuer::Mastercard),
+	("master card", Issuer::Mastercard),
+	("visa inc", Issuer::Visa),
+	("diners", Issuer::DinersClub),
+	("china unionpay", Issuer::UnionPay),
+	("cup", Issuer::UnionPay),
+	("bc", Issuer::BcCard),
+];
+
 impl Issuer {
-	pub fn name(self) -> &'static str {
+	/// Every issuer, in declaration order.
+	pub const ALL: &'static [Issuer] = {
+		use Issuer::*;
+
+		&[
+			AmericanExpress,
+			ChinaTUnion,
+			UnionPay,
+			DinersClub,
+			Discover,
+			UkrCard,
+			RuPay,
+			InterPayment,
+			InstaPayment,
+			Jcb,
+			MaestroUk,
+			Maestro,
+			Dankort,
+			Mir,
+			Borica,
+			Mastercard,
+			Troy,
+			Visa,
+			VisaElectron,
+			Uatp,
+			Verve,
+			LankaPay,
+			Gpn,
+			Switch,
+			Solo,
+			Laser,
+			BcCard,
+			EnRoute,
+		]
+	};
+
+	/// The stable integer representation of this issuer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::Visa.as_u16(), 17);
+	/// ```
+	#[must_use]
+	pub const fn as_u16(self) -> u16 {
+		self as u16
+	}
+
+	/// The position of this issuer in [`Issuer::ALL`], always less than
+	/// [`ISSUER_COUNT`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::ALL[Issuer::Visa.index()], Issuer::Visa);
+	/// ```
+	#[must_use]
+	pub const fn index(self) -> usize {
+		self as usize
+	}
+
+	/// The issuer with the stable integer representation `value`, if any.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::from_u16(17), Some(Issuer::Visa));
+	/// assert_eq!(Issuer::from_u16(u16::MAX), None);
+	/// ```
+	#[must_use]
+	pub fn from_u16(value: u16) -> Option<Issuer> {
+		Issuer::ALL
+			.iter()
+			.copied()
+			.find(|issuer| issuer.as_u16() == value)
+	}
+
+	/// Finds an issuer by its [`name`](Issuer::name) or a common alias such
+	/// as `amex` or `MC`, ignoring ASCII case.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::from_name_ci("visa"), Some(Issuer::Visa));
+	/// assert_eq!(Issuer::from_name_ci("Amex"), Some(Issuer::AmericanExpress));
+	/// assert_eq!(Issuer::from_name_ci("xyz"), None);
+	/// ```
+	#[must_use]
+	pub fn from_name_ci(name: &str) -> Option<Issuer> {
+		let names = Issuer::ALL.iter().map(|&issuer| (issuer.name(), issuer));
+
+		names
+			.chain(NAME_ALIASES.iter().copied())
+			.find(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+			.map(|(_, issuer)| issuer)
+	}
+
+	#[must_use]
+	pub const fn name(self) -> &'static str {
 		use Issuer::*;
 
 		match self {
@@ -123,355 +541,2556 @@ impl Issuer {
 			Verve => "Verve",
 			LankaPay => "LankaPay",
 			Gpn => "GPN",
+			Switch => "Switch",
+			Solo => "Solo",
+			Laser => "Laser",
+			BcCard => "BC Card",
+			EnRoute => "enRoute",
 		}
 	}
 
-	fn is_length_valid(self, len: usize) -> bool {
+	/// The length of [`Issuer::name`] in bytes, which for these ascii names
+	/// is also its display width.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::Visa.name_len(), 4);
+	/// ```
+	#[must_use]
+	pub const fn name_len(self) -> usize {
+		self.name().len()
+	}
+
+	/// The issuer's brand color as a `#RRGGBB` hex string, for UI badges.
+	///
+	/// Networks without a well-known brand color use a neutral gray.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::Visa.brand_color(), "#1A1F71");
+	/// assert_eq!(Issuer::Mastercard.brand_color(), "#EB001B");
+	/// ```
+	#[must_use]
+	pub const fn brand_color(self) -> &'static str {
 		use Issuer::*;
 
 		match self {
-			AmericanExpress => len == 15,
-			ChinaTUnion => len == 19,
-			UnionPay => (16..=19).contains(&len),
-			DinersClub => (14..=19).contains(&len),
-			Discover => (16..=19).contains(&len),
-			UkrCard => (16..=19).contains(&len),
-			RuPay => len == 16,
-			InterPayment => (16..=19).contains(&len),
-			InstaPayment => len == 16,
-			Jcb => (16..=19).contains(&len),
-			MaestroUk => (12..=19).contains(&len),
-			Maestro => (12..=19).contains(&len),
-			Dankort => len == 16,
-			Mir => (16..=19).contains(&len),
-			Borica => len == 16,
-			Mastercard => len == 16,
-			Troy => len == 16,
-			Visa => len == 13 || len == 16 || len == 19,
-			VisaElectron => len == 16,
-			Uatp => len == 15,
-			Verve => len == 16 || len == 18 || len == 19,
-			LankaPay => len == 16,
-			Gpn => len == 16 || len == 18 || len == 19,
+			AmericanExpress => "#2E77BC",
+			ChinaTUnion => "#0066B3",
+			UnionPay => "#E21836",
+			DinersClub => "#0079BE",
+			Discover => "#FF6000",
+			RuPay => "#097A44",
+			Jcb => "#0E4C96",
+			Maestro | MaestroUk => "#0099DF",
+			Dankort => "#ED1C24",
+			Mir => "#0F754E",
+			Mastercard => "#EB001B",
+			Troy => "#00A3AD",
+			Visa | VisaElectron => "#1A1F71",
+			Verve => "#00425F",
+			Gpn => "#ED1C24",
+			BcCard => "#F04B55",
+			UkrCard | InterPayment | InstaPayment | Borica | Uatp | LankaPay | Switch | Solo | Laser
+			| EnRoute => "#6B7280",
 		}
 	}
-}
-
-/// A credit card number.
-///
-/// # Example
-///
-/// ```
-/// use creditcard::CreditCard;
-///
-/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
-///
-/// assert_eq!(card.issuer(), creditcard::Issuer::Visa);
-/// assert_eq!(card.pan(), 4111111111111111);
-/// ```
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct CreditCard {
-	pan: u64,
-	issuer: Issuer,
-}
 
-impl CreditCard {
-	/// Parse a credit card number from a string.
+	/// The most common length of this issuer's card numbers.
 	///
-	/// Forwards to [`FromStr::from_str`].
+	/// This is a hint for things like input placeholders, not a validator;
+	/// many issuers allow other lengths too.
 	///
 	/// # Example
 	///
 	/// ```
-	/// use creditcard::CreditCard;
+	/// use creditcard::Issuer;
 	///
-	/// let card = CreditCard::parse_str("4111111111111111").unwrap();
+	/// assert_eq!(Issuer::Visa.typical_length(), 16);
+	/// assert_eq!(Issuer::AmericanExpress.typical_length(), 15);
 	/// ```
-	pub fn parse_str(card: &str) -> Result<CreditCard, Error> {
-		card.parse()
+	#[must_use]
+	pub const fn typical_length(self) -> usize {
+		match self {
+			Issuer::AmericanExpress | Issuer::Uatp | Issuer::EnRoute => 15,
+			Issuer::DinersClub => 14,
+			Issuer::ChinaTUnion => 19,
+			_ => 16,
+		}
 	}
 
-	/// The kind of credit card.
+	/// How many digits this issuer's BINs have, either 6 or 8.
+	///
+	/// ISO/IEC 7812-1 moved BINs from 6 to 8 digits in 2022, so this is 8
+	/// for most networks. American Express kept 6-digit BINs, as did the
+	/// networks discontinued before the migration.
 	///
 	/// # Example
 	///
 	/// ```
-	/// use creditcard::{CreditCard, Issuer};
-	///
-	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	/// use creditcard::Issuer;
 	///
-	/// assert_eq!(card.issuer(), Issuer::Visa);
+	/// assert_eq!(Issuer::Visa.bin_length(), 8);
+	/// assert_eq!(Issuer::AmericanExpress.bin_length(), 6);
 	/// ```
-	pub fn issuer(&self) -> Issuer {
-		self.issuer
+	#[must_use]
+	pub const fn bin_length(self) -> u8 {
+		match self {
+			Issuer::AmericanExpress | Issuer::Switch | Issuer::Solo | Issuer::Laser | Issuer::EnRoute => {
+				6
+			}
+			_ => 8,
+		}
 	}
 
-	/// The credit card number.
+	/// How many leading digits identify this issuer.
+	///
+	/// For issuers with several IIN ranges, this is the longest of them.
 	///
 	/// # Example
 	///
 	/// ```
-	/// use creditcard::CreditCard;
-	///
-	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	/// use creditcard::Issuer;
 	///
-	/// assert_eq!(card.pan(), 4111111111111111);
+	/// assert_eq!(Issuer::Visa.iin_significant_digits(), 1);
+	/// assert_eq!(Issuer::Discover.iin_significant_digits(), 6);
 	/// ```
-	pub fn pan(&self) -> u64 {
-		self.pan
+	#[must_use]
+	pub fn iin_significant_digits(self) -> u8 {
+		iin::IIN_RANGES
+			.iter()
+			.filter(|range| range.issuer == self)
+			.map(|range| range.digits)
+			.max()
+			.unwrap_or(0)
 	}
-}
 
-impl FromStr for CreditCard {
-	type Err = Error;
+	/// The lowest and highest 8-digit IIN across all of this issuer's
+	/// ranges.
+	///
+	/// The span may include IINs belonging to other issuers.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::Borica.iin_bounds(), (22050000, 22059999));
+	/// ```
+	#[must_use]
+	pub fn iin_bounds(self) -> (u32, u32) {
+		iin::IIN_RANGES
+			.iter()
+			.filter(|range| range.issuer == self)
+			.fold((u32::MAX, 0), |(low, high), range| {
+				(low.min(range.low), high.max(range.high))
+			})
+	}
 
-	fn from_str(card: &str) -> Result<Self, Self::Err> {
-		let pan = card
-			.parse::<u64>()
-			.map_err(|_| Error::InvalidFormat)?;
+	/// Whether an 8-digit IIN falls in one of this issuer's ranges.
+	///
+	/// Precedence between overlapping ranges is not applied, so an IIN can
+	/// match more than one issuer. Use [`CreditCard::issuer`] for the
+	/// definitive classification.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert!(Issuer::Visa.prefix_matches(41111111));
+	/// assert!(!Issuer::Mastercard.prefix_matches(41111111));
+	/// ```
+	#[must_use]
+	pub fn prefix_matches(self, iin8: u32) -> bool {
+		iin::IIN_RANGES
+			.iter()
+			.any(|range| range.issuer == self && (range.low..=range.high).contains(&iin8))
+	}
 
-		// all characters are ascii 0-9
-		let bytes = card.as_bytes();
+	/// The parent scheme of a sub-brand, or the issuer itself for standalone
+	/// networks.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert_eq!(Issuer::VisaElectron.scheme_family(), Issuer::Visa);
+	/// assert_eq!(Issuer::Visa.scheme_family(), Issuer::Visa);
+	/// ```
+	#[must_use]
+	pub fn scheme_family(self) -> Issuer {
+		use Issuer::*;
 
-		if bytes.len() < 12 || bytes[0] == b'0' {
-			return Err(Error::UnknownType);
+		match self {
+			VisaElectron => Visa,
+			// Switch was rebranded as Maestro UK
+			MaestroUk | Switch => Maestro,
+			EnRoute => DinersClub,
+			issuer => issuer,
 		}
+	}
 
-		// all IINs are at most 8 digits
-		let iin = u32::from_str(&card[..8]).unwrap();
-
-		// check in increase order of IIN length
-		#[allow(clippy::inconsistent_digit_grouping)]
-		let issuer = match iin {
-			// 8
-			60400100_..=60420099_ => Issuer::UkrCard,
-			// 6
-			506099_00..=506198_99 | 650002_00..=650027_99 | 507865_00..=507964_99 => {
-				Issuer::Verve
-			}
-			622126_00..=622925_99 => Issuer::Discover,
-			417500_00..=417500_99 => Issuer::VisaElectron,
-			357111_00..=357111_99 => Issuer::LankaPay,
-			676770_00..=676770_99 | 676774_00..=676774_99 => Issuer::MaestroUk,
-			// 4
-			6011_0000..=6011_9999 => Issuer::Discover,
-			3528_0000..=3589_9999 => Issuer::Jcb,
-			6759_0000..=6759_9999 => Issuer::MaestroUk,
-			5018_0000..=5018_9999
-			| 5020_0000..=5020_9999
-			| 5038_0000..=5038_9999
-			| 5893_0000..=5893_9999
-			| 6304_0000..=6304_9999
-			| 6761_0000..=6763_9999 => Issuer::Maestro,
-			5019_0000..=5019_9999 => Issuer::Dankort,
-			2200_0000..=2204_9999 => Issuer::Mir,
-			2205_0000..=2205_9999 => Issuer::Borica,
-			2221_0000..=2720_9999 => Issuer::Mastercard,
-			9792_0000..=9792_9999 => Issuer::Troy,
-			4026_0000..=4026_9999
-			| 4508_0000..=4508_9999
-			| 4844_0000..=4844_9999
-			| 4913_0000..=4913_9999
-			| 4917_0000..=4917_9999 => Issuer::VisaElectron,
-			1946_0000..=1946_9999 => Issuer::Gpn,
-			// 3
-			644_00000..=649_99999 => Issuer::Discover,
-			508_00000..=508_99999 => Issuer::RuPay,
-			636_00000..=636_99999 => Issuer::InterPayment,
-			637_00000..=639_99999 => Issuer::InstaPayment,
-			// 2
-			34_000000..=34_999999 | 37_000000..=37_999999 => Issuer::AmericanExpress,
-			31_000000..=31_999999 => Issuer::ChinaTUnion,
-			62_000000..=62_999999 => Issuer::UnionPay,
-			30_000000..=30_999999
-			| 36_000000..=36_999999
-			| 38_000000..=38_999999
-			| 39_000000..=39_999999 => Issuer::DinersClub,
-			65_000000..=65_999999 => Issuer::Discover,
-			60_000000..=60_999999 | 81_000000..=81_999999 | 82_000000..=82_999999 => {
-				Issuer::RuPay
-			}
-			51_000000..=55_999999 => Issuer::Mastercard,
-			50_000000..=50_999999
-			| 56_000000..=56_999999
-			| 58_000000..=58_999999
-			| 60_000000..=63_999999 => Issuer::Gpn,
-			// 1
-			4_0000000..=4_9999999 => Issuer::Visa,
-			1_0000000..=1_9999999 => Issuer::Uatp,
-			_ => return Err(Error::UnknownType),
-		};
-
-		if !issuer.is_length_valid(bytes.len()) {
-			return Err(Error::InvalidLength);
-		}
+	/// A valid example card number belonging to this issuer, for use in
+	/// documentation and fixtures.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// let card = Issuer::Visa.example();
+	///
+	/// assert_eq!(card.issuer(), Issuer::Visa);
+	/// assert_eq!(card.pan(), 4111111111111111);
+	/// ```
+	#[must_use]
+	pub const fn example(self) -> CreditCard {
+		use Issuer::*;
 
-		if !luhn::is_valid(bytes) {
-			return Err(Error::InvalidLuhn);
+		match self {
+			AmericanExpress => card!("378282246310005"),
+			ChinaTUnion => card!("3100000000000000005"),
+			UnionPay => card!("6200000000000005"),
+			DinersClub => card!("36000000000008"),
+			Discover => card!("6011111111111117"),
+			UkrCard => card!("6040010000000008"),
+			RuPay => card!("5080000000000002"),
+			InterPayment => card!("6360000000000001"),
+			InstaPayment => card!("6370000000000009"),
+			Jcb => card!("3566002020360505"),
+			MaestroUk => card!("6759000000000000"),
+			Maestro => card!("5018000000000009"),
+			Dankort => card!("5019000000000008"),
+			Mir => card!("2200000000000004"),
+			Borica => card!("2205000000000009"),
+			Mastercard => card!("5555555555554444"),
+			Troy => card!("9792000000000003"),
+			Visa => card!("4111111111111111"),
+			VisaElectron => card!("4026000000000002"),
+			Uatp => card!("100000000000009"),
+			Verve => card!("5060990000000008"),
+			LankaPay => card!("3571110000000000"),
+			Gpn => card!("1946000000000005"),
+			Switch => card!("4903000000000000"),
+			Solo => card!("6334000000000004"),
+			Laser => card!("6706000000000004"),
+			BcCard => card!("6541000000000003"),
+			EnRoute => card!("201400000000009"),
 		}
-
-		Ok(CreditCard {
-			pan,
-			issuer,
-		})
 	}
-}
 
-#[cfg(test)]
-mod test {
-	use super::*;
+	/// Whether `card` is a valid card number belonging to this issuer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert!(Issuer::Visa.accepts("4111111111111111"));
+	/// assert!(!Issuer::Mastercard.accepts("4111111111111111"));
+	/// ```
+	#[must_use]
+	pub fn accepts(self, card: &str) -> bool {
+		CreditCard::parse_str(card).is_ok_and(|card| card.issuer == self)
+	}
+
+	/// Checks that a card number of `len` digits is valid for this issuer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{Error, Issuer};
+	///
+	/// let amex = Issuer::AmericanExpress;
+	///
+	/// assert_eq!(amex.accepts_length(15), Ok(()));
+	/// assert_eq!(amex.accepts_length(16), Err(Error::InvalidLength));
+	/// ```
+	#[must_use = "the length is only validated if the result is checked"]
+	pub fn accepts_length(self, len: usize) -> Result<(), Error> {
+		if self.is_length_valid(len) {
+			Ok(())
+		} else {
+			Err(Error::InvalidLength)
+		}
+	}
+
+	/// The lengths this issuer allows, as either a contiguous range or a
+	/// list of discrete lengths.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{Issuer, LengthKind};
+	///
+	/// assert_eq!(Issuer::UnionPay.length_kind(), LengthKind::Range(16, 19));
+	/// assert_eq!(Issuer::Visa.length_kind(), LengthKind::Discrete(&[13, 16, 19]));
+	/// ```
+	#[must_use]
+	pub const fn length_kind(self) -> LengthKind {
+		use Issuer::*;
+
+		match self {
+			Visa => LengthKind::Discrete(&[13, 16, 19]),
+			Verve | Gpn | Switch | Solo => LengthKind::Discrete(&[16, 18, 19]),
+			AmericanExpress | Uatp | EnRoute => LengthKind::Range(15, 15),
+			ChinaTUnion => LengthKind::Range(19, 19),
+			DinersClub => LengthKind::Range(14, 19),
+			MaestroUk | Maestro => LengthKind::Range(12, 19),
+			UnionPay | Discover | UkrCard | InterPayment | Jcb | Mir | Laser => LengthKind::Range(16, 19),
+			RuPay | InstaPayment | Dankort | Borica | Mastercard | Troy | VisaElectron | LankaPay
+			| BcCard => LengthKind::Range(16, 16),
+		}
+	}
+
+	/// Whether card numbers of this issuer must pass the Luhn check.
+	///
+	/// Diners Club enRoute numbers never carry a check digit, and UnionPay
+	/// numbers are exempt with the `unionpay-no-luhn` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::Issuer;
+	///
+	/// assert!(Issuer::Visa.requires_luhn());
+	/// assert!(!Issuer::EnRoute.requires_luhn());
+	/// ```
+	#[must_use]
+	pub const fn requires_luhn(self) -> bool {
+		match self {
+			Issuer::UnionPay => !cfg!(feature = "unionpay-no-luhn"),
+			Issuer::EnRoute => false,
+			_ => true,
+		}
+	}
+
+	const fn is_length_valid(self, len: usize) -> bool {
+		use Issuer::*;
+
+		match self {
+			AmericanExpress => len == 15,
+			ChinaTUnion => len == 19,
+			UnionPay => matches!(len, 16..=19),
+			DinersClub => matches!(len, 14..=19),
+			Discover => matches!(len, 16..=19),
+			UkrCard => matches!(len, 16..=19),
+			RuPay => len == 16,
+			InterPayment => matches!(len, 16..=19),
+			InstaPayment => len == 16,
+			Jcb => matches!(len, 16..=19),
+			MaestroUk => matches!(len, 12..=19),
+			Maestro => matches!(len, 12..=19),
+			Dankort => len == 16,
+			Mir => matches!(len, 16..=19),
+			Borica => len == 16,
+			Mastercard => len == 16,
+			Troy => len == 16,
+			Visa => matches!(len, 13 | 16 | 19),
+			VisaElectron => len == 16,
+			Uatp => len == 15,
+			Verve => matches!(len, 16 | 18 | 19),
+			LankaPay => len == 16,
+			Gpn => matches!(len, 16 | 18 | 19),
+			Switch => matches!(len, 16 | 18 | 19),
+			Solo => matches!(len, 16 | 18 | 19),
+			Laser => matches!(len, 16..=19),
+			BcCard => len == 16,
+			EnRoute => len == 15,
+		}
+	}
+}
+
+/// A credit card number.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::CreditCard;
+///
+/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+///
+/// assert_eq!(card.issuer(), creditcard::Issuer::Visa);
+/// assert_eq!(card.pan(), 4111111111111111);
+/// ```
+///
+/// Cards are ordered numerically by number, so shorter numbers sort first.
+/// The same number can only be classified differently through an
+/// [`IssuerTable`], in which case the issuer breaks the tie.
+///
+/// With the default `redact-debug` feature, [`Debug`](fmt::Debug) masks all
+/// but the last four digits, as it does for [`CardParts`] and [`Parser`], so
+/// cards can't leak into logs through `{:?}`.
+/// Disabling it prints the full number, which should only be done where
+/// debug output never reaches logs, crash reports or anything else outside
+/// a trusted environment.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+pub struct CreditCard {
+	pan: u64,
+	issuer: Issuer,
+}
+
+impl CreditCard {
+	/// Parse a credit card number from a string.
+	///
+	/// This is what [`FromStr::from_str`] forwards to, unless the
+	/// `separators` feature is enabled. Since it is a `const fn`, it can also
+	/// validate numbers at compile time; see [`card!`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = CreditCard::parse_str("4111111111111111").unwrap();
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub const fn parse_str(card: &str) -> Result<CreditCard, Error> {
+		Self::from_bytes(card.as_bytes())
+	}
+
+	/// Parse a credit card number from its ascii digits.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = CreditCard::from_bytes(b"4111111111111111").unwrap();
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub const fn from_bytes(bytes: &[u8]) -> Result<CreditCard, Error> {
+		Self::from_bytes_with(bytes, &[])
+	}
+
+	/// Parse a credit card number from a C string, such as one received
+	/// across an FFI boundary.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = CreditCard::from_cstr(c"4111111111111111").unwrap();
+	/// ```
+	#[cfg(feature = "ffi")]
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn from_cstr(card: &core::ffi::CStr) -> Result<CreditCard, Error> {
+		Self::from_bytes(card.to_bytes())
+	}
+
+	/// Like [`CreditCard::from_bytes`], consulting `overrides` before the
+	/// built-in IIN ranges.
+	const fn from_bytes_with(
+		bytes: &[u8],
+		overrides: &[iin::ExtendedIinRange],
+	) -> Result<CreditCard, Error> {
+		match Self::validate_ascii(bytes) {
+			Ok(pan) => Self::from_ascii_with(bytes, pan, overrides),
+			Err(error) => Err(error),
+		}
+	}
+
+	/// Parse a credit card number from a string, also returning the byte
+	/// offset the error applies to.
+	///
+	/// The offset is that of the first non-digit for
+	/// [`Error::InvalidFormat`], of the check digit for
+	/// [`Error::InvalidLuhn`], and 0 for errors about the number as a whole.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Error};
+	///
+	/// let card = CreditCard::parse_detailed_err("4111x11111111111");
+	///
+	/// assert_eq!(card, Err((Error::InvalidFormat, 4)));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_detailed_err(card: &str) -> Result<CreditCard, (Error, usize)> {
+		Self::parse_str(card).map_err(|error| {
+			let offset = match error {
+				Error::InvalidFormat => card.bytes().position(|b| !b.is_ascii_digit()),
+				Error::InvalidLuhn => card.len().checked_sub(1),
+				_ => None,
+			};
+
+			(error, offset.unwrap_or(0))
+		})
+	}
+
+	/// Parse a credit card number from a string, also returning the issuer
+	/// when one was detected before the number failed.
+	///
+	/// The issuer is only known for [`Error::InvalidLength`] and
+	/// [`Error::InvalidLuhn`], so a UI can still say which kind of card has
+	/// the wrong number of digits.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Error, Issuer};
+	///
+	/// let card = CreditCard::parse_str_keep_issuer("555555555555444");
+	///
+	/// assert_eq!(card, Err((Error::InvalidLength, Some(Issuer::Mastercard))));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_keep_issuer(card: &str) -> Result<CreditCard, (Error, Option<Issuer>)> {
+		Self::parse_str(card).map_err(|error| {
+			let bytes = card.as_bytes();
+			// only the leading digits matter for the issuer, and 19 always fit
+			let len = bytes.len().min(19);
+
+			let issuer = match error {
+				Error::InvalidLength | Error::InvalidLuhn if bytes[0] != b'0' => {
+					iin::lookup_with(iin::EXTENDED_IIN_RANGES, ascii_to_pan(&bytes[..len]), len)
+				}
+				_ => None,
+			};
+
+			(error, issuer)
+		})
+	}
+
+	/// Parse a credit card number from a string, rejecting well-known
+	/// sandbox numbers published by payment gateways.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Error};
+	///
+	/// let card = CreditCard::parse_str_reject_test("4111111111111111");
+	///
+	/// assert_eq!(card, Err(Error::TestCard));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_reject_test(card: &str) -> Result<CreditCard, Error> {
+		let card = Self::parse_str(card)?;
+
+		if TEST_CARDS.contains(&card.pan) {
+			return Err(Error::TestCard);
+		}
+
+		Ok(card)
+	}
+
+	/// Parse a credit card number from a string, rejecting numbers that are
+	/// obviously made up.
+	///
+	/// Ignoring the check digit, a number is rejected with
+	/// [`Error::SuspiciousPattern`] if its digits are all the same, or each
+	/// is one more or one less than the previous, wrapping around at 9.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Error};
+	///
+	/// let card = CreditCard::parse_str_strict("4444444444444448");
+	///
+	/// assert_eq!(card, Err(Error::SuspiciousPattern));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_strict(card: &str) -> Result<CreditCard, Error> {
+		let digits = card.as_bytes();
+		let card = Self::parse_str(card)?;
+		let payload = &digits[..digits.len() - 1];
+
+		let steps = |step: u8| {
+			payload
+				.windows(2)
+				.all(|pair| (pair[0] - b'0' + step) % 10 == pair[1] - b'0')
+		};
+
+		if steps(0) || steps(1) || steps(9) {
+			return Err(Error::SuspiciousPattern);
+		}
+
+		Ok(card)
+	}
+
+	/// Parse a credit card number from a string, only accepting issuers in
+	/// `allowed`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Error, Issuer, IssuerSet};
+	///
+	/// let allowed = IssuerSet::from_iter([Issuer::Visa]);
+	///
+	/// assert!(CreditCard::parse_str_allowed("4111111111111111", allowed).is_ok());
+	/// assert_eq!(
+	///     CreditCard::parse_str_allowed("5555555555554444", allowed),
+	///     Err(Error::IssuerNotAllowed)
+	/// );
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_allowed(card: &str, allowed: IssuerSet) -> Result<CreditCard, Error> {
+		let card = Self::parse_str(card)?;
+
+		if !allowed.contains(card.issuer) {
+			return Err(Error::IssuerNotAllowed);
+		}
+
+		Ok(card)
+	}
+
+	/// Parse a credit card number from a string, reporting Maestro UK cards
+	/// as [`Issuer::Maestro`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// let card = CreditCard::parse_str_merge_maestro("6759649826438453").unwrap();
+	///
+	/// assert_eq!(card.issuer(), Issuer::Maestro);
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_merge_maestro(card: &str) -> Result<CreditCard, Error> {
+		let mut card = Self::parse_str(card)?;
+
+		if card.issuer == Issuer::MaestroUk {
+			card.issuer = Issuer::Maestro;
+		}
+
+		Ok(card)
+	}
+
+	/// Parse a credit card number from its digits, each in the range
+	/// 0..=9.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let digits = [4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1];
+	/// let card = CreditCard::from_digits(digits).unwrap();
+	///
+	/// assert_eq!(card.pan(), 4111111111111111);
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn from_digits<I: IntoIterator<Item = u8>>(digits: I) -> Result<CreditCard, Error> {
+		let mut bytes = [0; 19];
+		let mut len = 0;
+
+		for digit in digits {
+			if digit > 9 {
+				return Err(Error::InvalidFormat);
+			}
+
+			if len == bytes.len() {
+				return Err(Error::InvalidLength);
+			}
+
+			bytes[len] = b'0' + digit;
+			len += 1;
+		}
+
+		Self::from_bytes(&bytes[..len])
+	}
+
+	/// The kind of credit card.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.issuer(), Issuer::Visa);
+	/// ```
+	#[must_use]
+	pub fn issuer(&self) -> Issuer {
+		self.issuer
+	}
+
+	/// Whether the card is a Visa, not counting Visa Electron.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert!(card.is_visa());
+	/// ```
+	#[must_use]
+	pub fn is_visa(&self) -> bool {
+		self.issuer == Issuer::Visa
+	}
+
+	/// Whether the card is a Mastercard.
+	#[must_use]
+	pub fn is_mastercard(&self) -> bool {
+		self.issuer == Issuer::Mastercard
+	}
+
+	/// Whether the card is an American Express.
+	#[must_use]
+	pub fn is_amex(&self) -> bool {
+		self.issuer == Issuer::AmericanExpress
+	}
+
+	/// The display name of the issuer, short for `card.issuer().name()`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.issuer_name(), "Visa");
+	/// ```
+	#[must_use]
+	pub fn issuer_name(&self) -> &'static str {
+		self.issuer.name()
+	}
+
+	/// The credit card number.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.pan(), 4111111111111111);
+	/// ```
+	#[must_use]
+	pub fn pan(&self) -> u64 {
+		self.pan
+	}
+
+	/// Re-parses a credit card number, ignoring any previously stored
+	/// issuer.
+	///
+	/// Useful for detecting whether a stored card would be classified
+	/// differently after the IIN ranges are updated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// assert_eq!(CreditCard::reclassify(4111111111111111), Ok(Issuer::Visa));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn reclassify(pan: u64) -> Result<Issuer, Error> {
+		let mut buf = [0; 20];
+		let bytes = pan_to_ascii(pan, &mut buf);
+
+		Self::from_bytes(bytes).map(|card| card.issuer)
+	}
+
+	/// Classifies a card number by its IIN alone, skipping the length and
+	/// Luhn checks.
+	///
+	/// Since the number is an integer, card numbers with a leading zero
+	/// cannot be represented; such numbers belong to no issuer anyway.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// assert_eq!(CreditCard::issuer_from_pan(4111111111111112), Ok(Issuer::Visa));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub const fn issuer_from_pan(pan: u64) -> Result<Issuer, Error> {
+		if pan == 0 {
+			return Err(Error::UnknownType);
+		}
+
+		let len = pan.ilog10() as usize + 1;
+
+		if len > 19 {
+			return Err(Error::InvalidLength);
+		}
+
+		match iin::lookup_with(iin::EXTENDED_IIN_RANGES, pan, len) {
+			Some(issuer) => Ok(issuer),
+			None => Err(Error::UnknownType),
+		}
+	}
+
+	/// Builds a card of `length` digits from `payload`, the number without
+	/// its check digit, appending a computed Luhn check digit.
+	///
+	/// Returns [`Error::InvalidLength`] if `payload` does not have exactly
+	/// `length - 1` digits, or [`Error::UnknownType`] if the resulting number
+	/// is not classified as `issuer`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// let card = CreditCard::with_payload(Issuer::Visa, 411111111111111, 16).unwrap();
+	///
+	/// assert_eq!(card.pan(), 4111111111111111);
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn with_payload(issuer: Issuer, payload: u64, length: usize) -> Result<CreditCard, Error> {
+		let mut buf = [0; 20];
+		let digits = pan_to_ascii(payload, &mut buf);
+
+		if length > 19 || digits.len() + 1 != length {
+			return Err(Error::InvalidLength);
+		}
+
+		let pan = payload * 10 + u64::from(luhn::check_digit(digits));
+		let card = Self::from_bytes(pan_to_ascii(pan, &mut [0; 20]))?;
+
+		if card.issuer != issuer {
+			return Err(Error::UnknownType);
+		}
+
+		Ok(card)
+	}
+
+	/// Parse a credit card number from a string, replacing its last digit
+	/// with the correct Luhn check digit.
+	///
+	/// Every other check still runs, so numbers of an unknown type or the
+	/// wrong length are rejected as usual.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = CreditCard::with_corrected_check_digit("4111111111111112").unwrap();
+	///
+	/// assert_eq!(card.pan(), 4111111111111111);
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn with_corrected_check_digit(card: &str) -> Result<CreditCard, Error> {
+		let bytes = card.as_bytes();
+
+		if bytes.is_empty() {
+			return Err(Error::Empty);
+		}
+
+		if !is_ascii_digits(bytes) {
+			return Err(Error::InvalidFormat);
+		}
+
+		if bytes.len() > 19 {
+			return Err(Error::InvalidLength);
+		}
+
+		let mut digits = [0; 19];
+		let digits = &mut digits[..bytes.len()];
+		let payload = &bytes[..bytes.len() - 1];
+
+		digits.copy_from_slice(bytes);
+		digits[payload.len()] = b'0' + luhn::check_digit(payload);
+
+		Self::from_bytes(digits)
+	}
+
+	/// Whether `other` has the same number as this card but a different
+	/// issuer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert!(!card.issuer_changed_since(&card));
+	/// ```
+	#[must_use]
+	pub fn issuer_changed_since(&self, other: &CreditCard) -> bool {
+		self.pan == other.pan && self.issuer != other.issuer
+	}
+
+	/// The BIN series of a Mastercard, or `None` for other issuers.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, MastercardSeries};
+	///
+	/// let card = "5555555555554444".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.mastercard_series(), Some(MastercardSeries::Five));
+	/// ```
+	#[must_use]
+	pub fn mastercard_series(&self) -> Option<MastercardSeries> {
+		if self.issuer != Issuer::Mastercard {
+			return None;
+		}
+
+		match self.pan / 10u64.pow(self.length() as u32 - 1) {
+			2 => Some(MastercardSeries::Two),
+			5 => Some(MastercardSeries::Five),
+			_ => None,
+		}
+	}
+}
+
+/// With the `separators` feature, spaces and hyphens between digits are
+/// ignored, so `4111 1111 1111 1111` and `411111111111111 1` both parse.
+impl FromStr for CreditCard {
+	type Err = Error;
+
+	fn from_str(card: &str) -> Result<Self, Self::Err> {
+		if cfg!(feature = "separators") {
+			let mut buf = [0; 19];
+
+			return Self::from_bytes(normalize::normalize(card.as_bytes(), &mut buf)?);
+		}
+
+		Self::parse_str(card)
+	}
+}
+
+/// Equivalent to [`str::parse`], so spaces and hyphens are ignored with the
+/// `separators` feature.
+impl TryFrom<&str> for CreditCard {
+	type Error = Error;
+
+	fn try_from(card: &str) -> Result<Self, Self::Error> {
+		card.parse()
+	}
+}
+
+impl TryFrom<&[u8]> for CreditCard {
+	type Error = Error;
+
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		Self::from_bytes(bytes)
+	}
+}
+
+/// Parses an array of digits, each in the range 0..=9, as with
+/// [`CreditCard::from_digits`].
+impl<const N: usize> TryFrom<[u8; N]> for CreditCard {
+	type Error = Error;
+
+	fn try_from(digits: [u8; N]) -> Result<Self, Self::Error> {
+		Self::from_digits(digits)
+	}
+}
+
+/// Parses a credit card number literal at compile time.
+///
+/// An invalid number fails the build instead of panicking at runtime.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{CreditCard, Issuer, card};
+///
+/// const CARD: CreditCard = card!("4111111111111111");
+///
+/// assert_eq!(CARD.issuer(), Issuer::Visa);
+/// ```
+///
+/// ```compile_fail
+/// let card = creditcard::card!("4111111111111112");
+/// ```
+#[macro_export]
+macro_rules! card {
+	($card:literal) => {{
+		// a named constant rather than an inline `const` block, since only the
+		// former is evaluated by `cargo check`
+		const CARD: $crate::CreditCard = match $crate::CreditCard::parse_str($card) {
+			Ok(card) => card,
+			Err(error) => panic!("{}", error.as_str()),
+		};
+
+		CARD
+	}};
+}
+
+/// Whether `card` is a valid credit card number.
+///
+/// Equivalent to checking that [`str::parse`] succeeds.
+///
+/// # Example
+///
+/// ```
+/// assert!(creditcard::is_valid("4111111111111111"));
+/// assert!(!creditcard::is_valid("4111111111111112"));
+/// ```
+#[must_use]
+pub fn is_valid(card: &str) -> bool {
+	card.parse::<CreditCard>().is_ok()
+}
+
+/// The Luhn checksum of `digits` modulo 10, which is 0 for a valid
+/// checksum.
+///
+/// Unlike parsing, the issuer and length are not checked.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(creditcard::luhn_residue("4111111111111111"), Ok(0));
+/// assert_eq!(creditcard::luhn_residue("4111111111111112"), Ok(1));
+/// ```
+#[must_use = "the digits are only validated if the result is checked"]
+pub fn luhn_residue(digits: &str) -> Result<u8, Error> {
+	let bytes = digits.as_bytes();
+
+	if bytes.is_empty() || !is_ascii_digits(bytes) {
+		return Err(Error::InvalidFormat);
+	}
+
+	Ok(luhn::residue(bytes))
+}
+
+/// Classifies a card number by issuer, treating an unrecognised issuer as a
+/// result rather than an error.
+///
+/// Unrecognised numbers must still be 12 to 19 digits and pass the Luhn
+/// check, or [`Error::InvalidLength`] and [`Error::InvalidLuhn`] are
+/// returned. Every other error, including [`Error::UnknownType`] for a
+/// leading zero, is the one parsing returns.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{Classification, Issuer};
+///
+/// assert_eq!(
+///     creditcard::classify("4111111111111111"),
+///     Ok(Classification::Known(Issuer::Visa))
+/// );
+/// assert_eq!(
+///     creditcard::classify("70000000000005"),
+///     Ok(Classification::Unknown)
+/// );
+/// ```
+#[must_use = "the card is only validated if the result is checked"]
+pub fn classify(card: &str) -> Result<Classification, Error> {
+	let bytes = card.as_bytes();
+
+	match CreditCard::parse_str(card) {
+		Ok(card) => Ok(Classification::Known(card.issuer)),
+		Err(Error::UnknownType) if !(12..=19).contains(&bytes.len()) => Err(Error::InvalidLength),
+		Err(Error::UnknownType) if bytes[0] == b'0' => Err(Error::UnknownType),
+		Err(Error::UnknownType) if luhn::is_valid(bytes) => Ok(Classification::Unknown),
+		Err(Error::UnknownType) => Err(Error::InvalidLuhn),
+		Err(error) => Err(error),
+	}
+}
+
+/// Classifies a card number by issuer, also returning how confident that
+/// classification is.
+///
+/// Broad ranges like Discover's 65 have narrower ranges of other issuers
+/// carved out of them, so a number in one is more likely to be
+/// misclassified if the IIN tables are out of date.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{Confidence, Issuer};
+///
+/// assert_eq!(
+///     creditcard::classify_with_confidence("378282246310005"),
+///     Ok((Issuer::AmericanExpress, Confidence::High))
+/// );
+/// ```
+#[must_use = "the card is only validated if the result is checked"]
+pub fn classify_with_confidence(card: &str) -> Result<(Issuer, Confidence), Error> {
+	let card = CreditCard::parse_str(card)?;
+
+	let confidence = match card.decisive_prefix_len() {
+		// extended ranges are specific enough on their own
+		9.. => Confidence::High,
+		_ => match iin::sharing_issuers(iin::iin8(card.pan, card.length())) {
+			0 => Confidence::High,
+			1 => Confidence::Medium,
+			_ => Confidence::Low,
+		},
+	};
+
+	Ok((card.issuer, confidence))
+}
+
+impl CreditCard {
+	/// The number of digits in the card number.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4222222222222".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.length(), 13);
+	/// ```
+	#[must_use]
+	pub fn length(&self) -> usize {
+		self.pan.ilog10() as usize + 1
+	}
+
+	/// Writes the digits of the card number into `buf` as ascii, returning
+	/// the filled part.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4222222222222".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.to_ascii(&mut [0; 19]), b"4222222222222");
+	/// ```
+	#[must_use]
+	pub fn to_ascii<'a>(&self, buf: &'a mut [u8; 19]) -> &'a [u8] {
+		let mut digits = [0; 20];
+		let digits = pan_to_ascii(self.pan, &mut digits);
+		let buf = &mut buf[..digits.len()];
+
+		buf.copy_from_slice(digits);
+		buf
+	}
+
+	/// Whether both cards have the same number, regardless of how each was
+	/// classified.
+	///
+	/// Unlike `==`, this still holds for the same number parsed against
+	/// different IIN tables, which is what deduplication usually wants.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer, IssuerTable};
+	///
+	/// let table: IssuerTable = IssuerTable::new().with_override(41111111, 41111111, Issuer::UnionPay);
+	/// let a = CreditCard::parse_str("4111111111111111").unwrap();
+	/// let b = CreditCard::parse_str_with("4111111111111111", &table).unwrap();
+	///
+	/// assert!(a.same_number(&b));
+	/// assert_ne!(a, b);
+	/// ```
+	#[must_use]
+	pub fn same_number(&self, other: &CreditCard) -> bool {
+		self.pan == other.pan
+	}
+
+	/// Runs every check that does not depend on the issuer, returning the
+	/// number `bytes` spell.
+	///
+	/// Shared with [`IssuerCache`], so that cached parsing only skips the IIN
+	/// lookup.
+	const fn validate_ascii(bytes: &[u8]) -> Result<u64, Error> {
+		if bytes.is_empty() {
+			return Err(Error::Empty);
+		}
+
+		if !is_ascii_digits(bytes) {
+			return Err(Error::InvalidFormat);
+		}
+
+		// no issuer allows more than 19 digits, which also always fit in a u64
+		if bytes.len() > 19 {
+			return Err(Error::InvalidLength);
+		}
+
+		if bytes.len() < 12 || bytes[0] == b'0' {
+			return Err(Error::UnknownType);
+		}
+
+		Ok(ascii_to_pan(bytes))
+	}
+
+	/// Classifies an already validated number, consulting `overrides` before
+	/// the built-in IIN ranges.
+	///
+	/// `bytes` must be the ascii digits of `pan`.
+	const fn from_ascii_with(
+		bytes: &[u8],
+		pan: u64,
+		overrides: &[iin::ExtendedIinRange],
+	) -> Result<CreditCard, Error> {
+		let issuer = match iin::lookup_extended(overrides, pan, bytes.len()) {
+			Some(issuer) => issuer,
+			None => match iin::lookup_with(iin::EXTENDED_IIN_RANGES, pan, bytes.len()) {
+				Some(issuer) => issuer,
+				None => return Err(Error::UnknownType),
+			},
+		};
+
+		Self::from_ascii_issuer(bytes, pan, issuer)
+	}
+
+	/// Runs the length and Luhn checks for a card already classified as
+	/// `issuer`.
+	///
+	/// `bytes` must be the ascii digits of `pan`.
+	const fn from_ascii_issuer(bytes: &[u8], pan: u64, issuer: Issuer) -> Result<CreditCard, Error> {
+		if !issuer.is_length_valid(bytes.len()) {
+			return Err(Error::InvalidLength);
+		}
+
+		if issuer.requires_luhn() && !luhn::is_valid(bytes) {
+			return Err(Error::InvalidLuhn);
+		}
+
+		Ok(CreditCard { pan, issuer })
+	}
+}
+
+/// Publicly documented gateway test numbers.
+const TEST_CARDS: &[u64] = &[
+	// Visa
+	4111111111111111,
+	4242424242424242,
+	4012888888881881,
+	4222222222222,
+	4000056655665556,
+	4000002500003155,
+	// Mastercard
+	5555555555554444,
+	5105105105105100,
+	5200828282828210,
+	2223003122003222,
+	// American Express
+	378282246310005,
+	371449635398431,
+	378734493671000,
+	// Discover
+	6011111111111117,
+	6011000990139424,
+	// Diners Club
+	30569309025904,
+	38520000023237,
+	// JCB
+	3530111333300000,
+	3566002020360505,
+	// UnionPay
+	6200000000000005,
+];
+
+/// Whether every byte is an ascii digit.
+///
+/// With the `constant-time` feature, every byte is checked regardless of
+/// where the first non-digit is.
+const fn is_ascii_digits(bytes: &[u8]) -> bool {
+	let mut i = 0;
+
+	if cfg!(feature = "constant-time") {
+		let mut invalid = false;
+
+		while i < bytes.len() {
+			invalid |= bytes[i].wrapping_sub(b'0') > 9;
+			i += 1;
+		}
+
+		return !invalid;
+	}
+
+	while i < bytes.len() {
+		if !bytes[i].is_ascii_digit() {
+			return false;
+		}
+
+		i += 1;
+	}
+
+	true
+}
+
+/// `bytes` must be at most 19 ascii digits.
+const fn ascii_to_pan(bytes: &[u8]) -> u64 {
+	let mut pan = 0;
+	let mut i = 0;
+
+	while i < bytes.len() {
+		pan = pan * 10 + (bytes[i] - b'0') as u64;
+		i += 1;
+	}
+
+	pan
+}
+
+/// Writes the decimal digits of `pan` into `buf`, returning the used tail.
+fn pan_to_ascii(mut pan: u64, buf: &mut [u8; 20]) -> &[u8] {
+	let mut start = buf.len();
+
+	loop {
+		start -= 1;
+		buf[start] = b'0' + (pan % 10) as u8;
+		pan /= 10;
+
+		if pan == 0 {
+			break;
+		}
+	}
+
+	&buf[start..]
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_visa() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4111111111111111);
+
+		let card = "4012888888881881".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4012888888881881);
+
+		let card = "4222222222222".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4222222222222);
+	}
+
+	#[test]
+	fn test_parse_visa_electron_boundary() {
+		for (card, issuer) in [
+			("4175000000000001", Issuer::VisaElectron),
+			("4175009999999990", Issuer::VisaElectron),
+			("4175010000000000", Issuer::Visa),
+			("4174999999999993", Issuer::Visa),
+		] {
+			assert_eq!(card.parse::<CreditCard>().unwrap().issuer(), issuer);
+		}
+	}
+
+	#[test]
+	fn test_parse_visa_electron_four_digit_ranges() {
+		for (card, issuer) in [
+			("4025999999999994", Issuer::Visa),
+			("4026000000000002", Issuer::VisaElectron),
+			("4027000000000001", Issuer::Visa),
+			("4508000000000009", Issuer::VisaElectron),
+			("4509000000000008", Issuer::Visa),
+			("4844000000000002", Issuer::VisaElectron),
+			("4913000000000008", Issuer::VisaElectron),
+			("4917000000000004", Issuer::VisaElectron),
+			("4918000000000003", Issuer::Visa),
+		] {
+			assert_eq!(
+				card.parse::<CreditCard>().unwrap().issuer(),
+				issuer,
+				"{card}"
+			);
+		}
+
+		// a length only Visa allows does not make it Visa
+		assert_eq!(
+			"4026000000001".parse::<CreditCard>(),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			"4027000000009".parse::<CreditCard>().unwrap().issuer(),
+			Issuer::Visa
+		);
+	}
+
+	#[test]
+	fn test_parse_mastercard() {
+		let card = "5555555555554444".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Mastercard);
+		assert_eq!(card.pan(), 5555555555554444);
+
+		let card = "5105105105105100".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Mastercard);
+		assert_eq!(card.pan(), 5105105105105100);
+	}
+
+	#[test]
+	fn test_parse_amex() {
+		let card = "378282246310005".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::AmericanExpress);
+		assert_eq!(card.pan(), 378282246310005);
+
+		let card = "371449635398431".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::AmericanExpress);
+		assert_eq!(card.pan(), 371449635398431);
+	}
+
+	#[test]
+	fn test_parse_discover_union_pay_boundary() {
+		for (card, issuer) in [
+			("6221260000000000", Issuer::Discover),
+			("6229259999999992", Issuer::Discover),
+			("6221259999999990", Issuer::UnionPay),
+			("6229260000000002", Issuer::UnionPay),
+			("6221000000000000", Issuer::UnionPay),
+		] {
+			assert_eq!(card.parse::<CreditCard>().unwrap().issuer(), issuer);
+		}
+	}
+
+	#[test]
+	fn test_parse_interpayment_discover_boundary() {
+		for (card, issuer) in [
+			("6359999999999995", Issuer::Gpn),
+			("6360000000000001", Issuer::InterPayment),
+			("6369999999999993", Issuer::InterPayment),
+			("6370000000000009", Issuer::InstaPayment),
+			("6399999999999997", Issuer::InstaPayment),
+			("6440000000000005", Issuer::Discover),
+			("6499999999999996", Issuer::Discover),
+			("6500000000000002", Issuer::Discover),
+		] {
+			assert_eq!(card.parse::<CreditCard>().unwrap().issuer(), issuer);
+		}
+
+		for card in ["6400000000000003", "6439999999999999"] {
+			assert_eq!(card.parse::<CreditCard>(), Err(Error::UnknownType));
+		}
+	}
+
+	#[test]
+	fn test_parse_maestro_mastercard_boundary() {
+		for (card, issuer) in [
+			("5018000000000009", Issuer::Maestro),
+			("5019000000000008", Issuer::Dankort),
+			("5020000000000005", Issuer::Maestro),
+			("5021000000000004", Issuer::Gpn),
+			("5038000000000005", Issuer::Maestro),
+			("5099999999999992", Issuer::Gpn),
+			("5105000000000003", Issuer::Mastercard),
+			("5893000000000009", Issuer::Maestro),
+			("5894000000000008", Issuer::Gpn),
+		] {
+			assert_eq!(
+				card.parse::<CreditCard>().unwrap().issuer(),
+				issuer,
+				"{card}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_discover() {
+		let card = "6011111111111117".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Discover);
+		assert_eq!(card.pan(), 6011111111111117);
+
+		let card = "6011000990139424".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Discover);
+		assert_eq!(card.pan(), 6011000990139424);
+	}
+
+	#[test]
+	fn test_parse_diners_club() {
+		let card = "30569309025904".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::DinersClub);
+		assert_eq!(card.pan(), 30569309025904);
+
+		let card = "38520000023237".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::DinersClub);
+		assert_eq!(card.pan(), 38520000023237);
+
+		// Carte Blanche
+		let card = "30010000000003".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::DinersClub);
+	}
+
+	#[test]
+	fn test_parse_jcb() {
+		let card = "3530111333300000".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Jcb);
+		assert_eq!(card.pan(), 3530111333300000);
+
+		let card = "3566002020360505".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Jcb);
+		assert_eq!(card.pan(), 3566002020360505);
+	}
+
+	#[test]
+	fn test_parse_union_pay() {
+		let card = "6200000000000005".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::UnionPay);
+		assert_eq!(card.pan(), 6200000000000005);
+
+		let card = "6200000000000000000".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::UnionPay);
+		assert_eq!(card.pan(), 6200000000000000000);
+	}
+
+	#[test]
+	fn test_requires_luhn() {
+		assert!(Issuer::Visa.requires_luhn());
+		assert!(Issuer::Mastercard.requires_luhn());
+		assert!(!Issuer::EnRoute.requires_luhn());
+		assert_eq!(
+			Issuer::UnionPay.requires_luhn(),
+			!cfg!(feature = "unionpay-no-luhn")
+		);
+	}
+
+	#[test]
+	fn test_parse_union_pay_no_luhn() {
+		let result = "6200000000000001".parse::<CreditCard>();
+
+		if cfg!(feature = "unionpay-no-luhn") {
+			assert_eq!(result.unwrap().issuer(), Issuer::UnionPay);
+		} else {
+			assert_eq!(result, Err(Error::InvalidLuhn));
+		}
+
+		// only UnionPay is exempt
+		assert_eq!(
+			"4111111111111112".parse::<CreditCard>(),
+			Err(Error::InvalidLuhn)
+		);
+	}
+
+	#[test]
+	fn test_parse_mir() {
+		let card = "2200000000000004".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Mir);
+		assert_eq!(card.pan(), 2200000000000004);
+
+		let card = "2200999999999995".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Mir);
+		assert_eq!(card.pan(), 2200999999999995);
+	}
+
+	#[test]
+	fn test_parse_maestro_uk() {
+		let card = "6759649826438453".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::MaestroUk);
+		assert_eq!(card.pan(), 6759649826438453);
+
+		let card = "6763990100000000015".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Maestro);
+		assert_eq!(card.pan(), 6763990100000000015);
+	}
+
+	#[test]
+	fn test_parse_switch() {
+		let card = "4903111111111113".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Switch);
+		assert_eq!(card.pan(), 4903111111111113);
+
+		let card = "5641821111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Switch);
+		assert_eq!(card.pan(), 5641821111111111);
+
+		let card = "633110111111111118".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Switch);
+		assert_eq!(card.pan(), 633110111111111118);
+
+		let card = "6333111111111111118".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Switch);
+		assert_eq!(card.pan(), 6333111111111111118);
+
+		// carved out of Visa, so a length only Visa allows is rejected
+		assert_eq!(
+			"4903000000001".parse::<CreditCard>(),
+			Err(Error::InvalidLength)
+		);
+
+		// shared with Maestro UK, which takes precedence
+		let card = "6759649826438453".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::MaestroUk);
+	}
+
+	#[test]
+	fn test_parse_solo() {
+		let card = "6334222222222220".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Solo);
+		assert_eq!(card.pan(), 6334222222222220);
+
+		let card = "676722222222222220".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Solo);
+		assert_eq!(card.pan(), 676722222222222220);
+
+		// carved out by Maestro UK, which takes precedence
+		for (card, issuer) in [
+			("6767700000000005", Issuer::MaestroUk),
+			("6767740000000001", Issuer::MaestroUk),
+			("6767710000000004", Issuer::Solo),
+		] {
+			assert_eq!(
+				card.parse::<CreditCard>().unwrap().issuer(),
+				issuer,
+				"{card}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_laser() {
+		let card = "6706333333333333".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Laser);
+		assert_eq!(card.pan(), 6706333333333333);
+
+		let card = "67093333333333336".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Laser);
+		assert_eq!(card.pan(), 67093333333333336);
+
+		let card = "6771333333333333337".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Laser);
+		assert_eq!(card.pan(), 6771333333333333337);
+
+		// shared with Maestro, which takes precedence
+		let card = "6304333333333339".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Maestro);
+	}
+
+	#[test]
+	fn test_parse_verve() {
+		for card in [
+			"5060990000000008",
+			"506099000000000008",
+			"5078650000000000004",
+			"6500020000000000",
+			"650027999999999992",
+			"5061989999999999993",
+		] {
+			let card = card.parse::<CreditCard>().unwrap();
+
+			assert_eq!(card.issuer(), Issuer::Verve);
+		}
+
+		assert_eq!(
+			"50609900000000001".parse::<CreditCard>(),
+			Err(Error::InvalidLength)
+		);
+	}
+
+	#[test]
+	fn test_parse_bc_card() {
+		let card = "6541444444444445".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::BcCard);
+		assert_eq!(card.pan(), 6541444444444445);
+
+		let card = "6556444444444447".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::BcCard);
+		assert_eq!(card.pan(), 6556444444444447);
+	}
+
+	#[test]
+	fn test_parse_en_route() {
+		let card = "201444444444449".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::EnRoute);
+		assert_eq!(card.pan(), 201444444444449);
+
+		let card = "214944444444443".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::EnRoute);
+		assert_eq!(card.pan(), 214944444444443);
+
+		// no check digit
+		let card = "201400000000001".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::EnRoute);
+
+		// does not reach the neighbouring Mir range
+		let card = "2204000000000000".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Mir);
+	}
+
+	#[test]
+	fn test_parse_separators() {
+		for card in [
+			"4111 1111 1111 1111",
+			"4111-1111-1111-1111",
+			"411111111111111 1",
+		] {
+			let result = card.parse::<CreditCard>();
+
+			if cfg!(feature = "separators") {
+				assert_eq!(result.unwrap().pan(), 4111111111111111);
+			} else {
+				assert_eq!(result, Err(Error::InvalidFormat));
+			}
+
+			// never stripped by the const entry point
+			assert_eq!(CreditCard::parse_str(card), Err(Error::InvalidFormat));
+		}
+	}
+
+	#[test]
+	fn test_invalid_format() {
+		let card = "4111111111111111a".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidFormat));
+	}
+
+	#[test]
+	fn test_invalid_format_sign() {
+		let card = "+4111111111111111".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidFormat));
+
+		let card = "".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::Empty));
+	}
+
+	#[test]
+	fn test_unknown_type() {
+		let card = "0000000000000000".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::UnknownType));
+	}
+
+	#[test]
+	fn test_invalid_length() {
+		let card = "41111111111111111".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidLength));
+	}
 
 	#[test]
-	fn test_parse_visa() {
-		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	fn test_invalid_length_overflow() {
+		let card = "411111111111111111111".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidLength));
+
+		let card = "99999999999999999999999999".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidLength));
+	}
+
+	#[test]
+	fn test_invalid_luhn() {
+		let card = "4111111111111112".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidLuhn));
+
+		let card = "4111111111111113".parse::<CreditCard>();
+
+		assert_eq!(card, Err(Error::InvalidLuhn));
+	}
+
+	#[test]
+	fn test_brand_color() {
+		for issuer in Issuer::ALL {
+			let color = issuer.brand_color();
+
+			assert_eq!(color.len(), 7, "{issuer:?}");
+			assert!(color.starts_with('#'), "{issuer:?}");
+			assert!(
+				color[1..].bytes().all(|c| c.is_ascii_hexdigit()),
+				"{issuer:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_length_kind() {
+		assert_eq!(Issuer::UnionPay.length_kind(), LengthKind::Range(16, 19));
+		assert_eq!(Issuer::Mastercard.length_kind(), LengthKind::Range(16, 16));
+		assert_eq!(
+			Issuer::Visa.length_kind(),
+			LengthKind::Discrete(&[13, 16, 19])
+		);
+
+		for &issuer in Issuer::ALL {
+			for len in 0..=20 {
+				let allowed = match issuer.length_kind() {
+					LengthKind::Range(low, high) => (low..=high).contains(&len),
+					LengthKind::Discrete(lengths) => lengths.contains(&len),
+				};
+
+				assert_eq!(allowed, issuer.is_length_valid(len), "{issuer:?} {len}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_typical_length() {
+		assert_eq!(Issuer::AmericanExpress.typical_length(), 15);
+		assert_eq!(Issuer::ChinaTUnion.typical_length(), 19);
+		assert_eq!(Issuer::Visa.typical_length(), 16);
+
+		for &issuer in Issuer::ALL {
+			assert!(
+				issuer.is_length_valid(issuer.typical_length()),
+				"{issuer:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_bin_length() {
+		assert_eq!(Issuer::Visa.bin_length(), 8);
+		assert_eq!(Issuer::Mastercard.bin_length(), 8);
+		assert_eq!(Issuer::UkrCard.bin_length(), 8);
+		assert_eq!(Issuer::AmericanExpress.bin_length(), 6);
+		assert_eq!(Issuer::Laser.bin_length(), 6);
+	}
+
+	#[test]
+	fn test_from_name_ci() {
+		assert_eq!(Issuer::from_name_ci("amex"), Some(Issuer::AmericanExpress));
+		assert_eq!(Issuer::from_name_ci("AMEX"), Some(Issuer::AmericanExpress));
+		assert_eq!(Issuer::from_name_ci("MC"), Some(Issuer::Mastercard));
+		assert_eq!(Issuer::from_name_ci("mastercard"), Some(Issuer::Mastercard));
+		assert_eq!(Issuer::from_name_ci("Visa Inc"), Some(Issuer::Visa));
+		assert_eq!(Issuer::from_name_ci("xyz"), None);
+		assert_eq!(Issuer::from_name_ci(""), None);
+
+		for &issuer in Issuer::ALL {
+			assert_eq!(Issuer::from_name_ci(issuer.name()), Some(issuer));
+		}
+	}
+
+	#[test]
+	fn test_name_len() {
+		for &issuer in Issuer::ALL {
+			assert_eq!(issuer.name_len(), issuer.name().len());
+			assert!(issuer.name().is_ascii());
+		}
+
+		let longest = Issuer::ALL.iter().map(|issuer| issuer.name().len()).max();
+
+		assert_eq!(Some(MAX_ISSUER_NAME_LEN), longest);
+		assert_eq!(MAX_ISSUER_NAME_LEN, "American Express".len());
+	}
+
+	#[test]
+	fn test_repr() {
+		assert_eq!(Issuer::AmericanExpress as u16, 0);
+		assert_eq!(Issuer::Visa as u16, 17);
+		assert_eq!(Issuer::EnRoute as u16, 27);
+		assert_eq!(core::mem::size_of::<Issuer>(), 2);
+
+		for (i, &issuer) in Issuer::ALL.iter().enumerate() {
+			assert_eq!(issuer.as_u16(), i as u16);
+			assert_eq!(Issuer::from_u16(i as u16), Some(issuer));
+		}
+	}
+
+	#[test]
+	fn test_index() {
+		let mut seen = [false; ISSUER_COUNT];
+
+		for &issuer in Issuer::ALL {
+			assert!(issuer.index() < ISSUER_COUNT);
+			assert!(!seen[issuer.index()], "{issuer:?}");
+			assert_eq!(Issuer::ALL[issuer.index()], issuer);
+
+			seen[issuer.index()] = true;
+		}
+
+		assert_eq!(ISSUER_COUNT, 28);
+	}
+
+	#[test]
+	fn test_accepts() {
+		assert!(Issuer::Visa.accepts("4111111111111111"));
+		assert!(!Issuer::Mastercard.accepts("4111111111111111"));
+		assert!(!Issuer::VisaElectron.accepts("4111111111111111"));
+		assert!(!Issuer::Visa.accepts("4111111111111112"));
+	}
+
+	#[test]
+	fn test_scheme_family() {
+		assert_eq!(Issuer::VisaElectron.scheme_family(), Issuer::Visa);
+		assert_eq!(Issuer::MaestroUk.scheme_family(), Issuer::Maestro);
+		assert_eq!(Issuer::Switch.scheme_family(), Issuer::Maestro);
+		assert_eq!(Issuer::EnRoute.scheme_family(), Issuer::DinersClub);
+		assert_eq!(
+			Issuer::AmericanExpress.scheme_family(),
+			Issuer::AmericanExpress
+		);
+
+		for &issuer in Issuer::ALL {
+			let family = issuer.scheme_family();
+
+			assert_eq!(family.scheme_family(), family);
+		}
+	}
+
+	#[test]
+	fn test_iin_bounds() {
+		assert_eq!(Issuer::AmericanExpress.iin_bounds(), (34000000, 37999999));
+		assert_eq!(Issuer::Visa.iin_bounds(), (40000000, 49999999));
+		assert_eq!(Issuer::Discover.iin_bounds(), (60110000, 65999999));
+
+		for &issuer in Issuer::ALL {
+			let (low, high) = issuer.iin_bounds();
+
+			assert!(low <= high);
+		}
+	}
+
+	#[test]
+	fn test_issuer_from_pan() {
+		assert_eq!(
+			CreditCard::issuer_from_pan(4111111111111111),
+			Ok(Issuer::Visa)
+		);
+		assert_eq!(CreditCard::issuer_from_pan(41), Ok(Issuer::Visa));
+		assert_eq!(
+			CreditCard::issuer_from_pan(378282246310005),
+			Ok(Issuer::AmericanExpress)
+		);
+		assert_eq!(
+			CreditCard::issuer_from_pan(7111111111111111),
+			Err(Error::UnknownType)
+		);
+		assert_eq!(CreditCard::issuer_from_pan(0), Err(Error::UnknownType));
+		assert_eq!(
+			CreditCard::issuer_from_pan(u64::MAX),
+			Err(Error::InvalidLength)
+		);
+	}
+
+	#[test]
+	fn test_with_corrected_check_digit() {
+		let card = CreditCard::with_corrected_check_digit("4111111111111112").unwrap();
 
 		assert_eq!(card.issuer(), Issuer::Visa);
 		assert_eq!(card.pan(), 4111111111111111);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit("4111111111111111"),
+			Ok(card)
+		);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit("378282246310009")
+				.unwrap()
+				.pan(),
+			378282246310005
+		);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit("7111111111111112"),
+			Err(Error::UnknownType)
+		);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit("411111111111112"),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit("41111111111111111111"),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit("4111 1111"),
+			Err(Error::InvalidFormat)
+		);
+		assert_eq!(
+			CreditCard::with_corrected_check_digit(""),
+			Err(Error::Empty)
+		);
+	}
 
-		let card = "4012888888881881".parse::<CreditCard>().unwrap();
+	#[test]
+	fn test_with_payload() {
+		let card = CreditCard::with_payload(Issuer::AmericanExpress, 37828224631000, 15).unwrap();
 
-		assert_eq!(card.issuer(), Issuer::Visa);
-		assert_eq!(card.pan(), 4012888888881881);
+		assert_eq!(card.issuer(), Issuer::AmericanExpress);
+		assert_eq!(card.pan(), 378282246310005);
+		assert_eq!(
+			CreditCard::with_payload(Issuer::Visa, 37828224631000, 15),
+			Err(Error::UnknownType)
+		);
+		assert_eq!(
+			CreditCard::with_payload(Issuer::AmericanExpress, 37828224631000, 16),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			CreditCard::with_payload(Issuer::Visa, 411111111111111, 16)
+				.unwrap()
+				.pan(),
+			4111111111111111
+		);
+	}
+
+	#[test]
+	fn test_parse_str_keep_issuer() {
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer("5555555555554444")
+				.unwrap()
+				.pan(),
+			5555555555554444
+		);
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer("555555555555444"),
+			Err((Error::InvalidLength, Some(Issuer::Mastercard)))
+		);
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer("55555555555544440000"),
+			Err((Error::InvalidLength, Some(Issuer::Mastercard)))
+		);
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer("4111111111111112"),
+			Err((Error::InvalidLuhn, Some(Issuer::Visa)))
+		);
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer("7111111111111111"),
+			Err((Error::UnknownType, None))
+		);
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer("4111x"),
+			Err((Error::InvalidFormat, None))
+		);
+		assert_eq!(
+			CreditCard::parse_str_keep_issuer(""),
+			Err((Error::Empty, None))
+		);
+	}
+
+	#[test]
+	fn test_length() {
+		for (card, length) in [
+			("4222222222222", 13),
+			("4111111111111111", 16),
+			("6200000000000000000", 19),
+		] {
+			assert_eq!(card.parse::<CreditCard>().unwrap().length(), length);
+		}
+	}
 
+	#[test]
+	fn test_to_ascii() {
+		let mut buf = [0; 19];
 		let card = "4222222222222".parse::<CreditCard>().unwrap();
 
-		assert_eq!(card.issuer(), Issuer::Visa);
-		assert_eq!(card.pan(), 4222222222222);
+		assert_eq!(card.to_ascii(&mut buf), b"4222222222222");
+		assert_eq!(card.to_ascii(&mut buf).len(), 13);
+
+		let card = "6200000000000000000".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.to_ascii(&mut buf), b"6200000000000000000");
 	}
 
 	#[test]
-	fn test_parse_mastercard() {
+	fn test_same_number() {
+		let table: IssuerTable = IssuerTable::new().with_override(41111111, 41111111, Issuer::UnionPay);
+		let visa = CreditCard::parse_str("4111111111111111").unwrap();
+		let unionpay = CreditCard::parse_str_with("4111111111111111", &table).unwrap();
+
+		assert_eq!(unionpay.issuer(), Issuer::UnionPay);
+		assert!(visa.same_number(&unionpay));
+		assert_ne!(visa, unionpay);
+
+		let other = CreditCard::parse_str("4012888888881881").unwrap();
+
+		assert!(!visa.same_number(&other));
+	}
+
+	#[test]
+	fn test_issuer_predicates() {
+		let visa = "4111111111111111".parse::<CreditCard>().unwrap();
+		let mastercard = "5555555555554444".parse::<CreditCard>().unwrap();
+		let amex = "378282246310005".parse::<CreditCard>().unwrap();
+
+		assert!(visa.is_visa());
+		assert!(!visa.is_mastercard());
+		assert!(!visa.is_amex());
+		assert!(mastercard.is_mastercard());
+		assert!(!mastercard.is_visa());
+		assert!(amex.is_amex());
+		assert!(!amex.is_visa());
+	}
+
+	#[test]
+	fn test_mastercard_series() {
 		let card = "5555555555554444".parse::<CreditCard>().unwrap();
 
-		assert_eq!(card.issuer(), Issuer::Mastercard);
-		assert_eq!(card.pan(), 5555555555554444);
+		assert_eq!(card.mastercard_series(), Some(MastercardSeries::Five));
 
-		let card = "5105105105105100".parse::<CreditCard>().unwrap();
+		let card = "2221000000000009".parse::<CreditCard>().unwrap();
 
-		assert_eq!(card.issuer(), Issuer::Mastercard);
-		assert_eq!(card.pan(), 5105105105105100);
+		assert_eq!(card.mastercard_series(), Some(MastercardSeries::Two));
+
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.mastercard_series(), None);
 	}
 
 	#[test]
-	fn test_parse_amex() {
-		let card = "378282246310005".parse::<CreditCard>().unwrap();
+	fn test_parse_detailed_err() {
+		assert_eq!(
+			CreditCard::parse_detailed_err("4111x11111111111"),
+			Err((Error::InvalidFormat, 4))
+		);
+		assert_eq!(
+			CreditCard::parse_detailed_err("4111111111111112"),
+			Err((Error::InvalidLuhn, 15))
+		);
+		assert_eq!(
+			CreditCard::parse_detailed_err("7111111111111111"),
+			Err((Error::UnknownType, 0))
+		);
+		assert_eq!(CreditCard::parse_detailed_err(""), Err((Error::Empty, 0)));
+		assert_eq!(
+			CreditCard::parse_detailed_err("4111111111111111")
+				.unwrap()
+				.pan(),
+			4111111111111111
+		);
+	}
 
-		assert_eq!(card.issuer(), Issuer::AmericanExpress);
-		assert_eq!(card.pan(), 378282246310005);
+	#[test]
+	fn test_ord() {
+		let mut cards = [
+			"5555555555554444",
+			"4111111111111111",
+			"378282246310005",
+			"4012888888881881",
+		]
+		.map(|card| card.parse::<CreditCard>().unwrap());
+
+		cards.sort();
+
+		assert_eq!(cards.map(|card| card.pan()), [
+			378282246310005,
+			4012888888881881,
+			4111111111111111,
+			5555555555554444
+		]);
+	}
 
-		let card = "371449635398431".parse::<CreditCard>().unwrap();
+	#[test]
+	fn test_try_from() {
+		let card = CreditCard::try_from("4111111111111111").unwrap();
 
-		assert_eq!(card.issuer(), Issuer::AmericanExpress);
-		assert_eq!(card.pan(), 371449635398431);
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4111111111111111);
+		assert_eq!(CreditCard::try_from(&b"4111111111111111"[..]), Ok(card));
+		assert_eq!(
+			CreditCard::try_from(&b"4111 1111"[..]),
+			Err(Error::InvalidFormat)
+		);
+		assert_eq!(CreditCard::try_from(""), Err(Error::Empty));
 	}
 
+	#[cfg(feature = "separators")]
 	#[test]
-	fn test_parse_discover() {
-		let card = "6011111111111117".parse::<CreditCard>().unwrap();
+	fn test_try_from_separators() {
+		let card = CreditCard::try_from("4111 1111 1111 1111");
+
+		assert_eq!(card, "4111 1111 1111 1111".parse());
+		assert_eq!(card.unwrap().pan(), 4111111111111111);
+		assert_eq!(
+			CreditCard::try_from("4111-1111-1111-1112"),
+			"4111-1111-1111-1112".parse::<CreditCard>()
+		);
+	}
 
-		assert_eq!(card.issuer(), Issuer::Discover);
-		assert_eq!(card.pan(), 6011111111111117);
+	#[test]
+	fn test_try_from_digit_array() {
+		let digits: [u8; 16] = [4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1];
+		let card = CreditCard::try_from(digits).unwrap();
 
-		let card = "6011000990139424".parse::<CreditCard>().unwrap();
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4111111111111111);
 
-		assert_eq!(card.issuer(), Issuer::Discover);
-		assert_eq!(card.pan(), 6011000990139424);
+		let digits: [u8; 16] = [4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, b'1', 1, 1, 1];
+
+		assert_eq!(CreditCard::try_from(digits), Err(Error::InvalidFormat));
+		assert_eq!(CreditCard::try_from([4; 20]), Err(Error::InvalidLength));
+		assert_eq!(CreditCard::try_from([0u8; 0]), Err(Error::Empty));
 	}
 
+	#[cfg(feature = "ffi")]
 	#[test]
-	fn test_parse_diners_club() {
-		let card = "30569309025904".parse::<CreditCard>().unwrap();
+	fn test_from_cstr() {
+		use core::ffi::CStr;
 
-		assert_eq!(card.issuer(), Issuer::DinersClub);
-		assert_eq!(card.pan(), 30569309025904);
+		// as handed over from C, with whatever follows the terminator
+		let buf = *b"4111111111111111\0garbage";
+		let card = CreditCard::from_cstr(CStr::from_bytes_until_nul(&buf).unwrap()).unwrap();
 
-		let card = "38520000023237".parse::<CreditCard>().unwrap();
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4111111111111111);
 
-		assert_eq!(card.issuer(), Issuer::DinersClub);
-		assert_eq!(card.pan(), 38520000023237);
+		assert_eq!(
+			CreditCard::from_cstr(c"4111 1111"),
+			Err(Error::InvalidFormat)
+		);
+		assert_eq!(CreditCard::from_cstr(c""), Err(Error::Empty));
 	}
 
 	#[test]
-	fn test_parse_jcb() {
-		let card = "3530111333300000".parse::<CreditCard>().unwrap();
+	fn test_issuer_example() {
+		for &issuer in Issuer::ALL {
+			let example = issuer.example();
+			let mut buf = [0; 20];
+			let digits = core::str::from_utf8(pan_to_ascii(example.pan(), &mut buf)).unwrap();
+
+			assert_eq!(example.issuer(), issuer);
+			assert_eq!(digits.parse::<CreditCard>(), Ok(example));
+		}
+	}
 
-		assert_eq!(card.issuer(), Issuer::Jcb);
-		assert_eq!(card.pan(), 3530111333300000);
+	#[test]
+	fn test_accepts_length() {
+		fn check(issuer: Issuer, card: &str) -> Result<usize, Error> {
+			issuer.accepts_length(card.len())?;
 
-		let card = "3566002020360505".parse::<CreditCard>().unwrap();
+			Ok(card.len())
+		}
 
-		assert_eq!(card.issuer(), Issuer::Jcb);
-		assert_eq!(card.pan(), 3566002020360505);
+		assert_eq!(check(Issuer::Visa, "4222222222222"), Ok(13));
+		assert_eq!(
+			check(Issuer::Visa, "42222222222222"),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(Issuer::UnionPay.accepts_length(19), Ok(()));
+		assert_eq!(
+			Issuer::UnionPay.accepts_length(20),
+			Err(Error::InvalidLength)
+		);
 	}
 
 	#[test]
-	fn test_parse_union_pay() {
-		let card = "6200000000000005".parse::<CreditCard>().unwrap();
+	fn test_iin_significant_digits() {
+		assert_eq!(Issuer::Visa.iin_significant_digits(), 1);
+		assert_eq!(Issuer::AmericanExpress.iin_significant_digits(), 2);
+		assert_eq!(Issuer::UkrCard.iin_significant_digits(), 8);
+		assert_eq!(Issuer::Jcb.iin_significant_digits(), 4);
+	}
 
-		assert_eq!(card.issuer(), Issuer::UnionPay);
-		assert_eq!(card.pan(), 6200000000000005);
+	#[test]
+	fn test_prefix_matches() {
+		assert!(Issuer::Visa.prefix_matches(41111111));
+		assert!(!Issuer::Mastercard.prefix_matches(41111111));
+		assert!(Issuer::Mastercard.prefix_matches(55555555));
+		assert!(Issuer::Mastercard.prefix_matches(22210000));
+		assert!(!Issuer::Mastercard.prefix_matches(22200000));
+
+		// overlapping ranges match both issuers
+		assert!(Issuer::VisaElectron.prefix_matches(41750012));
+		assert!(Issuer::Visa.prefix_matches(41750012));
+	}
 
-		let card = "6200000000000000000".parse::<CreditCard>().unwrap();
+	#[test]
+	fn test_reject_test_card() {
+		for card in [
+			"4111111111111111",
+			"4242424242424242",
+			"5555555555554444",
+			"378282246310005",
+		] {
+			assert_eq!(
+				CreditCard::parse_str_reject_test(card),
+				Err(Error::TestCard)
+			);
+		}
 
-		assert_eq!(card.issuer(), Issuer::UnionPay);
-		assert_eq!(card.pan(), 6200000000000000000);
+		let card = CreditCard::parse_str_reject_test("4539578763621486").unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+
+		let card = CreditCard::parse_str_reject_test("4111111111111112");
+
+		assert_eq!(card, Err(Error::InvalidLuhn));
 	}
 
 	#[test]
-	fn test_parse_mir() {
-		let card = "2200000000000004".parse::<CreditCard>().unwrap();
+	fn test_parse_str_strict() {
+		for card in ["4444444444444448", "4567890123456783", "4321098765437"] {
+			assert!(card.parse::<CreditCard>().is_ok());
+			assert_eq!(
+				CreditCard::parse_str_strict(card),
+				Err(Error::SuspiciousPattern)
+			);
+		}
 
-		assert_eq!(card.issuer(), Issuer::Mir);
-		assert_eq!(card.pan(), 2200000000000004);
+		let card = CreditCard::parse_str_strict("4111111111111111").unwrap();
 
-		let card = "2200999999999995".parse::<CreditCard>().unwrap();
+		assert_eq!(card.pan(), 4111111111111111);
+		assert_eq!(
+			CreditCard::parse_str_strict("4444444444444444"),
+			Err(Error::InvalidLuhn)
+		);
+	}
 
-		assert_eq!(card.issuer(), Issuer::Mir);
-		assert_eq!(card.pan(), 2200999999999995);
+	#[test]
+	fn test_error_as_str() {
+		assert_eq!(Error::InvalidFormat.as_str(), "invalid card number format");
+		assert_eq!(Error::UnknownType.as_str(), "unknown card type");
+		assert_eq!(Error::InvalidLength.as_str(), "invalid card number length");
+		assert_eq!(Error::InvalidLuhn.as_str(), "invalid luhn checksum");
+		assert_eq!(Error::TestCard.as_str(), "known test card number");
+		assert_eq!(Error::IssuerNotAllowed.as_str(), "card issuer not allowed");
+		assert_eq!(Error::BufferTooSmall.as_str(), "buffer too small");
+		assert_eq!(
+			Error::SuspiciousPattern.as_str(),
+			"suspicious card number pattern"
+		);
+		assert_eq!(Error::Empty.as_str(), "empty card number");
 	}
 
 	#[test]
-	fn test_parse_maestro_uk() {
-		let card = "6759649826438453".parse::<CreditCard>().unwrap();
+	fn test_error_code() {
+		for (error, code, status) in [
+			(Error::InvalidFormat, "invalid_format", 422),
+			(Error::UnknownType, "unknown_type", 422),
+			(Error::InvalidLength, "invalid_length", 422),
+			(Error::InvalidLuhn, "invalid_luhn", 422),
+			(Error::TestCard, "test_card", 422),
+			(Error::IssuerNotAllowed, "issuer_not_allowed", 422),
+			(Error::BufferTooSmall, "buffer_too_small", 500),
+			(Error::SuspiciousPattern, "suspicious_pattern", 422),
+			(Error::Empty, "empty", 422),
+		] {
+			assert_eq!(error.code(), code);
+			assert_eq!(error.http_status(), status);
+		}
+	}
 
-		assert_eq!(card.issuer(), Issuer::MaestroUk);
-		assert_eq!(card.pan(), 6759649826438453);
+	#[test]
+	fn test_error_is_user_correctable() {
+		assert!(Error::InvalidFormat.is_user_correctable());
+		assert!(Error::InvalidLength.is_user_correctable());
+		assert!(Error::InvalidLuhn.is_user_correctable());
+		assert!(Error::Empty.is_user_correctable());
+		assert!(!Error::UnknownType.is_user_correctable());
+		assert!(!Error::TestCard.is_user_correctable());
+		assert!(!Error::IssuerNotAllowed.is_user_correctable());
+		assert!(!Error::BufferTooSmall.is_user_correctable());
+		assert!(!Error::SuspiciousPattern.is_user_correctable());
+	}
 
-		let card = "6763990100000000015".parse::<CreditCard>().unwrap();
+	#[test]
+	fn test_luhn_residue() {
+		assert_eq!(luhn_residue("4111111111111111"), Ok(0));
+		assert_eq!(luhn_residue("378282246310005"), Ok(0));
+		assert_eq!(luhn_residue("4111111111111112"), Ok(1));
+		assert_eq!(luhn_residue("4111111111111119"), Ok(8));
+		assert_eq!(luhn_residue("7"), Ok(7));
+		assert_eq!(luhn_residue(""), Err(Error::InvalidFormat));
+		assert_eq!(luhn_residue("4111-1111"), Err(Error::InvalidFormat));
+	}
 
-		assert_eq!(card.issuer(), Issuer::Maestro);
-		assert_eq!(card.pan(), 6763990100000000015);
+	#[test]
+	fn test_classify() {
+		assert_eq!(
+			classify("378282246310005"),
+			Ok(Classification::Known(Issuer::AmericanExpress))
+		);
+		assert_eq!(classify("70000000000005"), Ok(Classification::Unknown));
+		assert_eq!(classify("70000000000003"), Err(Error::InvalidLuhn));
+		assert_eq!(classify("4111111111111112"), Err(Error::InvalidLuhn));
+		assert_eq!(classify("7000-0000"), Err(Error::InvalidFormat));
+		assert_eq!(classify("18"), Err(Error::InvalidLength));
+		assert_eq!(classify("0"), Err(Error::InvalidLength));
+		assert_eq!(classify("0000000000000"), Err(Error::UnknownType));
+
+		for card in [
+			"0000000000000",
+			"0411111111111111",
+			"",
+			"7000-0000",
+			"41111111111111111111",
+		] {
+			assert_eq!(
+				classify(card).unwrap_err(),
+				CreditCard::parse_str(card).unwrap_err(),
+				"{card}"
+			);
+		}
 	}
 
 	#[test]
-	fn test_invalid_format() {
-		let card = "4111111111111111a".parse::<CreditCard>();
+	fn test_classify_with_confidence() {
+		for (card, issuer, confidence) in [
+			("378282246310005", Issuer::AmericanExpress, Confidence::High),
+			("5555555555554444", Issuer::Mastercard, Confidence::High),
+			("6011000990139424", Issuer::Discover, Confidence::High),
+			("5018000000000009", Issuer::Maestro, Confidence::High),
+			("5021000000000004", Issuer::Gpn, Confidence::Low),
+			// LankaPay's 357111 is carved out of JCB
+			("3530111333300000", Issuer::Jcb, Confidence::Medium),
+			("6500000000000002", Issuer::Discover, Confidence::Low),
+			("4111111111111111", Issuer::Visa, Confidence::Low),
+		] {
+			assert_eq!(
+				classify_with_confidence(card),
+				Ok((issuer, confidence)),
+				"{card}"
+			);
+		}
 
-		assert_eq!(card, Err(Error::InvalidFormat));
+		assert_eq!(
+			classify_with_confidence("4111111111111112"),
+			Err(Error::InvalidLuhn)
+		);
 	}
 
 	#[test]
-	fn test_unknown_type() {
-		let card = "0000000000000000".parse::<CreditCard>();
+	fn test_is_ascii_digits() {
+		for byte in 0..=u8::MAX {
+			assert_eq!(is_ascii_digits(&[byte]), byte.is_ascii_digit());
+			assert_eq!(is_ascii_digits(&[b'4', byte, b'1']), byte.is_ascii_digit());
+		}
 
-		assert_eq!(card, Err(Error::UnknownType));
+		assert!(is_ascii_digits(b""));
+		assert!(is_ascii_digits(b"4111111111111111"));
+		assert!(!is_ascii_digits(b"4111 1111"));
 	}
 
 	#[test]
-	fn test_invalid_length() {
-		let card = "41111111111111111".parse::<CreditCard>();
+	fn test_is_valid() {
+		assert!(is_valid("4111111111111111"));
+		assert!(!is_valid("4111111111111112"));
+		assert!(!is_valid("visa"));
+	}
+
+	#[test]
+	fn test_parse_str_merge_maestro() {
+		let card = "6759649826438453";
+
+		assert_eq!(
+			CreditCard::parse_str(card).unwrap().issuer(),
+			Issuer::MaestroUk
+		);
+		assert_eq!(
+			CreditCard::parse_str_merge_maestro(card).unwrap().issuer(),
+			Issuer::Maestro
+		);
+		assert_eq!(
+			CreditCard::parse_str_merge_maestro("6304000000000000")
+				.unwrap()
+				.issuer(),
+			Issuer::Maestro
+		);
+		assert_eq!(
+			CreditCard::parse_str_merge_maestro("4111111111111111")
+				.unwrap()
+				.issuer(),
+			Issuer::Visa
+		);
+		assert_eq!(
+			CreditCard::parse_str_merge_maestro("6759649826438454"),
+			Err(Error::InvalidLuhn)
+		);
+	}
+
+	#[test]
+	fn test_from_digits() {
+		let card = CreditCard::from_digits([4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]).unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4111111111111111);
+
+		let card = CreditCard::from_digits("378282246310005".bytes().map(|c| c - b'0')).unwrap();
+
+		assert_eq!(card.issuer(), Issuer::AmericanExpress);
+
+		let card = CreditCard::from_digits([4, 1, 1, 1, 1, 1, 10, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+
+		assert_eq!(card, Err(Error::InvalidFormat));
+
+		let card = CreditCard::from_digits([4; 20]);
 
 		assert_eq!(card, Err(Error::InvalidLength));
+
+		let card = CreditCard::from_digits([]);
+
+		assert_eq!(card, Err(Error::Empty));
 	}
 
 	#[test]
-	fn test_invalid_luhn() {
-		let card = "4111111111111112".parse::<CreditCard>();
+	fn test_parse_str_allowed() {
+		let allowed = IssuerSet::from_iter([Issuer::Visa, Issuer::Mastercard]);
 
-		assert_eq!(card, Err(Error::InvalidLuhn));
+		let card = CreditCard::parse_str_allowed("4111111111111111", allowed).unwrap();
 
-		let card = "4111111111111113".parse::<CreditCard>();
+		assert_eq!(card.issuer(), Issuer::Visa);
+
+		let card = CreditCard::parse_str_allowed("5555555555554444", allowed).unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Mastercard);
+
+		let card = CreditCard::parse_str_allowed("378282246310005", allowed);
+
+		assert_eq!(card, Err(Error::IssuerNotAllowed));
+
+		let card = CreditCard::parse_str_allowed("378282246310006", allowed);
 
 		assert_eq!(card, Err(Error::InvalidLuhn));
 	}
+
+	#[test]
+	fn test_card_macro() {
+		let card = card!("378282246310005");
+
+		assert_eq!(card.issuer(), Issuer::AmericanExpress);
+		assert_eq!(card.pan(), 378282246310005);
+		assert_eq!(card, "378282246310005".parse().unwrap());
+	}
+
+	#[test]
+	fn test_issuer_changed_since() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+		let stale = CreditCard {
+			pan: card.pan(),
+			issuer: Issuer::Mastercard,
+		};
+
+		assert!(card.issuer_changed_since(&stale));
+		assert!(stale.issuer_changed_since(&card));
+		assert!(!card.issuer_changed_since(&card));
+
+		let other = "5555555555554444".parse::<CreditCard>().unwrap();
+
+		assert!(!card.issuer_changed_since(&other));
+
+		assert_eq!(CreditCard::reclassify(stale.pan()), Ok(Issuer::Visa));
+		assert_eq!(
+			CreditCard::reclassify(4111111111111112),
+			Err(Error::InvalidLuhn)
+		);
+	}
 }
@@ -0,0 +1,49 @@
+use crate::{CreditCard, Error};
+
+/// Parses each line of `data`, split on `\n` with any trailing `\r`
+/// removed.
+///
+/// Empty lines are skipped.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::Error;
+///
+/// let mut cards = creditcard::validate_lines(b"4111111111111111\r\n1234\n");
+///
+/// assert_eq!(cards.next().unwrap().unwrap().pan(), 4111111111111111);
+/// assert_eq!(cards.next().unwrap(), Err(Error::UnknownType));
+/// assert!(cards.next().is_none());
+/// ```
+pub fn validate_lines(data: &[u8]) -> impl Iterator<Item = Result<CreditCard, Error>> + '_ {
+	data
+		.split(|&b| b == b'\n')
+		.map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+		.filter(|line| !line.is_empty())
+		.map(CreditCard::from_bytes)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_validate_lines() {
+		let data = b"4111111111111111\n\
+			378282246310005\r\n\
+			\n\
+			4111111111111112\n\
+			not a card\r\n\
+			5555555555554444";
+
+		let mut cards = validate_lines(data);
+
+		assert_eq!(cards.next().unwrap().unwrap().pan(), 4111111111111111);
+		assert_eq!(cards.next().unwrap().unwrap().pan(), 378282246310005);
+		assert_eq!(cards.next().unwrap(), Err(Error::InvalidLuhn));
+		assert_eq!(cards.next().unwrap(), Err(Error::InvalidFormat));
+		assert_eq!(cards.next().unwrap().unwrap().pan(), 5555555555554444);
+		assert!(cards.next().is_none());
+	}
+}
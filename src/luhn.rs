@@ -1,40 +1,208 @@
 //! Ported from https://github.com/valeriansaliou/rs-card-validate/blob/master/src/luhn.rs
 
 /// `card` must be a slice of bytes within the range 0..=9.
-pub(crate) fn is_valid(card: &[u8]) -> bool {
-	let checksum = card
-		.iter()
-		.copied()
-		.rev()
-		.zip(1usize..)
-		.fold(0, |mut checksum, (c, i)| {
-			let is_odd = i % 2 == 1;
+pub(crate) const fn is_valid(card: &[u8]) -> bool {
+	residue(card) == 0
+}
+
+/// The Luhn checksum modulo 10, which is 0 for a valid `card`.
+///
+/// `card` must be a slice of bytes within the range 0..=9.
+pub(crate) const fn residue(card: &[u8]) -> u8 {
+	(checksum(card, 1) % 10) as u8
+}
+
+/// The check digit that makes `payload` followed by it valid.
+///
+/// `payload` must be a slice of bytes within the range 0..=9.
+pub(crate) const fn check_digit(payload: &[u8]) -> u8 {
+	// the check digit takes position 1, pushing the payload over by one
+	((10 - checksum(payload, 2) % 10) % 10) as u8
+}
 
-			if is_odd {
-				checksum += checksum_modifier_odd(c);
-			} else {
-				checksum += checksum_modifier_even(c);
-			};
+/// `first` is the position of the last digit, counting from 1.
+///
+/// Written as an index loop rather than an iterator fold so it can run in
+/// `const` contexts.
+const fn checksum(card: &[u8], first: usize) -> u32 {
+	let mut checksum = 0;
+	let mut i = 0;
 
-			checksum
-		});
+	while i < card.len() {
+		let c = card[card.len() - 1 - i];
+		let is_odd = (first + i) % 2 == 1;
 
-	checksum % 10 == 0
+		if is_odd {
+			checksum += checksum_modifier_odd(c);
+		} else {
+			checksum += checksum_modifier_even(c);
+		};
+
+		i += 1;
+	}
+
+	checksum
 }
 
 #[inline(always)]
-fn checksum_modifier_odd(c: u8) -> u32 {
+const fn checksum_modifier_odd(c: u8) -> u32 {
 	numeric_char_to_u32(c)
 }
 
 #[inline(always)]
-fn checksum_modifier_even(c: u8) -> u32 {
-	let n = numeric_char_to_u32(c);
-	let d = n * 2;
-	if d <= 9 { d } else { d - 9 }
+const fn checksum_modifier_even(c: u8) -> u32 {
+	if cfg!(feature = "constant-time") {
+		checksum_modifier_even_constant_time(c)
+	} else {
+		let n = numeric_char_to_u32(c);
+		let d = n * 2;
+		if d <= 9 { d } else { d - 9 }
+	}
 }
 
+/// Like [`checksum_modifier_even`], without branching on the digit.
 #[inline(always)]
-fn numeric_char_to_u32(c: u8) -> u32 {
+const fn checksum_modifier_even_constant_time(c: u8) -> u32 {
+	let d = numeric_char_to_u32(c) * 2;
+
+	d - 9 * (d > 9) as u32
+}
+
+#[inline(always)]
+const fn numeric_char_to_u32(c: u8) -> u32 {
 	(c as u32) - ('0' as u32)
 }
+
+/// Whether `input` is valid under the Luhn mod N algorithm, where N is the
+/// number of `codepoints` and each character's value is its index among them.
+///
+/// With the digits `0` to `9` as the codepoints this is the regular Luhn
+/// algorithm. Empty inputs and characters outside `codepoints` are invalid.
+///
+/// # Example
+///
+/// ```
+/// let codepoints = ['a', 'b', 'c', 'd', 'e', 'f'];
+///
+/// assert!(creditcard::luhn_mod_n_is_valid("abcdefe", &codepoints));
+/// assert!(!creditcard::luhn_mod_n_is_valid("abcdefa", &codepoints));
+/// ```
+#[must_use]
+pub fn luhn_mod_n_is_valid(input: &str, codepoints: &[char]) -> bool {
+	let n = codepoints.len();
+	let mut sum = 0;
+
+	if input.is_empty() {
+		return false;
+	}
+
+	for (i, c) in input.chars().rev().enumerate() {
+		let Some(value) = codepoints.iter().position(|&codepoint| codepoint == c) else {
+			return false;
+		};
+
+		// every second character from the right is doubled, as in `checksum`
+		let addend = value * (1 + i % 2);
+
+		sum += addend / n + addend % n;
+	}
+
+	sum % n == 0
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_luhn_mod_n() {
+		let hex = ['a', 'b', 'c', 'd', 'e', 'f'];
+
+		assert!(luhn_mod_n_is_valid("abcdefe", &hex));
+
+		for c in ['a', 'b', 'c', 'd', 'f'] {
+			assert!(!luhn_mod_n_is_valid(&std::format!("abcdef{c}"), &hex));
+		}
+
+		let base36 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+			.chars()
+			.collect::<std::vec::Vec<_>>();
+
+		assert!(luhn_mod_n_is_valid("ABCDEC", &base36));
+		assert!(!luhn_mod_n_is_valid("ABCDED", &base36));
+		assert!(!luhn_mod_n_is_valid("abcdec", &base36));
+		assert!(!luhn_mod_n_is_valid("", &base36));
+
+		let digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+		assert!(luhn_mod_n_is_valid("4111111111111111", &digits));
+		assert!(!luhn_mod_n_is_valid("4111111111111112", &digits));
+	}
+
+	proptest::proptest! {
+		#[test]
+		fn test_check_digit_round_trip(payload in "[0-9]{12,18}") {
+			let mut card = payload.into_bytes();
+
+			card.push(b'0' + check_digit(&card));
+
+			proptest::prop_assert!(is_valid(&card));
+			proptest::prop_assert_eq!(residue(&card), 0);
+
+			// the check digit catches every single-digit error
+			for i in 0..card.len() {
+				let original = card[i];
+
+				for digit in (b'0'..=b'9').filter(|&digit| digit != original) {
+					card[i] = digit;
+					proptest::prop_assert!(!is_valid(&card));
+				}
+
+				card[i] = original;
+			}
+		}
+	}
+
+	#[test]
+	fn test_const_evaluation() {
+		const {
+			assert!(is_valid(b"4111111111111111"));
+			assert!(!is_valid(b"4111111111111112"));
+			assert!(residue(b"4111111111111112") == 1);
+			assert!(check_digit(b"411111111111111") == 1);
+		}
+	}
+
+	#[test]
+	fn test_constant_time_equivalence() {
+		for c in b'0'..=b'9' {
+			let n = numeric_char_to_u32(c) * 2;
+
+			assert_eq!(checksum_modifier_even(c), n % 10 + n / 10);
+			assert_eq!(checksum_modifier_even_constant_time(c), n % 10 + n / 10);
+		}
+
+		let mut pan = 4111111111111111u64;
+
+		for _ in 0..1000 {
+			let digits = std::format!("{pan}");
+			let expected = digits
+				.bytes()
+				.rev()
+				.enumerate()
+				.map(|(i, c)| {
+					let n = u32::from(c - b'0') * (1 + (i % 2) as u32);
+
+					n % 10 + n / 10
+				})
+				.sum::<u32>();
+
+			assert_eq!(is_valid(digits.as_bytes()), expected % 10 == 0);
+
+			pan = pan
+				.wrapping_mul(6364136223846793005)
+				.wrapping_add(1442695040888963407)
+				% 10u64.pow(16);
+		}
+	}
+}
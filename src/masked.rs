@@ -0,0 +1,271 @@
+use core::fmt;
+
+use crate::{
+	CreditCard, Error, Issuer,
+	iin::{self, Prefix},
+	pan_to_ascii,
+};
+
+/// A credit card reduced to its issuer and last four digits.
+///
+/// Since the full number is discarded, this is safe to log or store.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{CreditCard, Issuer};
+///
+/// let card = "4111111111111111".parse::<CreditCard>().unwrap().mask();
+///
+/// assert_eq!(card.issuer(), Issuer::Visa);
+/// assert_eq!(card.last_four(), 1111);
+/// assert_eq!(card.to_string(), "Visa ****1111");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaskedCard {
+	issuer: Issuer,
+	last_four: u16,
+}
+
+impl MaskedCard {
+	/// The kind of credit card.
+	#[must_use]
+	pub fn issuer(&self) -> Issuer {
+		self.issuer
+	}
+
+	/// The last four digits of the credit card number.
+	#[must_use]
+	pub fn last_four(&self) -> u16 {
+		self.last_four
+	}
+}
+
+impl fmt::Display for MaskedCard {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} ****{:04}", self.issuer.name(), self.last_four)
+	}
+}
+
+impl CreditCard {
+	/// Discards everything but the issuer and last four digits.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.mask().last_four(), 1111);
+	/// ```
+	#[must_use]
+	pub fn mask(self) -> MaskedCard {
+		MaskedCard {
+			issuer: self.issuer,
+			last_four: (self.pan % 10000) as u16,
+		}
+	}
+
+	/// Writes the card number into `buf` with all but the first `prefix`
+	/// and last `suffix` digits replaced by `*`.
+	///
+	/// Returns [`Error::InvalidLength`] if `prefix + suffix` is more than the
+	/// number of digits, or [`Error::BufferTooSmall`] if `buf` cannot hold
+	/// them.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	/// let mut buf = [0; 19];
+	///
+	/// assert_eq!(card.obfuscate(6, 4, &mut buf), Ok("411111******1111"));
+	/// ```
+	pub fn obfuscate<'a>(
+		&self,
+		prefix: usize,
+		suffix: usize,
+		buf: &'a mut [u8],
+	) -> Result<&'a str, Error> {
+		let mut digits = [0; 20];
+		let digits = pan_to_ascii(self.pan, &mut digits);
+
+		if prefix.saturating_add(suffix) > digits.len() {
+			return Err(Error::InvalidLength);
+		}
+
+		let buf = buf.get_mut(..digits.len()).ok_or(Error::BufferTooSmall)?;
+
+		buf.copy_from_slice(digits);
+		buf[prefix..digits.len() - suffix].fill(b'*');
+
+		// only ascii was written
+		Ok(core::str::from_utf8(buf).unwrap())
+	}
+
+	/// Parses an already-masked card number such as `411111******1111`,
+	/// returning its issuer and last four digits.
+	///
+	/// Masked digits may be `*`, `x` or `X`, and must form one run between at
+	/// least the first six and the last four digits. Since the middle digits
+	/// are unknown, the Luhn check is not run, even if nothing is masked.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// assert_eq!(CreditCard::parse_masked("411111******1111"), Ok((Issuer::Visa, 1111)));
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_masked(card: &str) -> Result<(Issuer, u16), Error> {
+		let bytes = card.as_bytes();
+		let is_mask = |c: &u8| matches!(c, b'*' | b'x' | b'X');
+
+		let prefix_len = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+		let mask_len = bytes[prefix_len..]
+			.iter()
+			.take_while(|c| is_mask(c))
+			.count();
+		let suffix = &bytes[prefix_len + mask_len..];
+
+		if bytes.is_empty() {
+			return Err(Error::Empty);
+		}
+
+		if !suffix.iter().all(u8::is_ascii_digit) {
+			return Err(Error::InvalidFormat);
+		}
+
+		if bytes.len() > 19 {
+			return Err(Error::InvalidLength);
+		}
+
+		if mask_len > 0 && (prefix_len < 6 || suffix.len() < 4) {
+			return Err(Error::UnknownType);
+		}
+
+		let Prefix::Unique(issuer) = iin::classify_prefix(&bytes[..prefix_len])? else {
+			return Err(Error::UnknownType);
+		};
+
+		issuer.accepts_length(bytes.len())?;
+
+		let last_four = bytes[bytes.len() - 4..]
+			.iter()
+			.fold(0, |n, &c| n * 10 + u16::from(c - b'0'));
+
+		Ok((issuer, last_four))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::string::ToString;
+
+	use super::*;
+
+	#[test]
+	fn test_mask() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap().mask();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.last_four(), 1111);
+		assert_eq!(card.to_string(), "Visa ****1111");
+
+		let card = "5105105105105100".parse::<CreditCard>().unwrap().mask();
+
+		assert_eq!(card.last_four(), 5100);
+		assert_eq!(card.to_string(), "Mastercard ****5100");
+
+		let card = "6011000990139424".parse::<CreditCard>().unwrap().mask();
+
+		assert_eq!(card.to_string(), "Discover ****9424");
+	}
+
+	#[test]
+	fn test_mask_leading_zeros() {
+		let card = "6200000000000005".parse::<CreditCard>().unwrap().mask();
+
+		assert_eq!(card.last_four(), 5);
+		assert_eq!(card.to_string(), "UnionPay ****0005");
+	}
+
+	#[test]
+	fn test_obfuscate() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+		let mut buf = [0; 19];
+
+		assert_eq!(card.obfuscate(6, 4, &mut buf), Ok("411111******1111"));
+		assert_eq!(card.obfuscate(0, 4, &mut buf), Ok("************1111"));
+		assert_eq!(card.obfuscate(8, 8, &mut buf), Ok("4111111111111111"));
+		assert_eq!(card.obfuscate(9, 8, &mut buf), Err(Error::InvalidLength));
+		assert_eq!(
+			card.obfuscate(usize::MAX, 1, &mut buf),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			card.obfuscate(6, 4, &mut [0; 15]),
+			Err(Error::BufferTooSmall)
+		);
+	}
+
+	#[test]
+	fn test_parse_masked() {
+		assert_eq!(
+			CreditCard::parse_masked("411111******1111"),
+			Ok((Issuer::Visa, 1111))
+		);
+		assert_eq!(
+			CreditCard::parse_masked("378282xxxxx0005"),
+			Ok((Issuer::AmericanExpress, 5))
+		);
+		assert_eq!(
+			CreditCard::parse_masked("51051051XXXX5100"),
+			Ok((Issuer::Mastercard, 5100))
+		);
+		assert_eq!(
+			CreditCard::parse_masked("4111111111111111"),
+			Ok((Issuer::Visa, 1111))
+		);
+		assert_eq!(CreditCard::parse_masked(""), Err(Error::Empty));
+		assert_eq!(
+			CreditCard::parse_masked("4111**11**111111"),
+			Err(Error::InvalidFormat)
+		);
+		assert_eq!(
+			CreditCard::parse_masked("411111#*****1111"),
+			Err(Error::InvalidFormat)
+		);
+		assert_eq!(
+			CreditCard::parse_masked("41111*******1111"),
+			Err(Error::UnknownType)
+		);
+		assert_eq!(
+			CreditCard::parse_masked("411111*******111"),
+			Err(Error::UnknownType)
+		);
+		assert_eq!(
+			CreditCard::parse_masked("411111*****1111"),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			CreditCard::parse_masked("411111**********1111"),
+			Err(Error::InvalidLength)
+		);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_mask_serde() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap().mask();
+		let json = serde_json::to_string(&card).unwrap();
+
+		assert_eq!(json, r#"{"issuer":"Visa","last_four":1111}"#);
+		assert_eq!(serde_json::from_str::<MaskedCard>(&json).unwrap(), card);
+	}
+}
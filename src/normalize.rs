@@ -0,0 +1,255 @@
+use crate::{CreditCard, Error, Issuer};
+
+/// Whether `byte` may separate groups of digits, as in `4111 1111 1111 1111`
+/// or `4111-1111-1111-1111`.
+pub(crate) fn is_separator(byte: u8) -> bool {
+	matches!(byte, b' ' | b'-')
+}
+
+/// Copies the digits of `card` into `buf`, skipping separators.
+///
+/// Any other character is an [`Error::InvalidFormat`], even past the 19th
+/// digit, so errors are reported in the same order as for unseparated input.
+pub(crate) fn normalize<'a>(card: &[u8], buf: &'a mut [u8; 19]) -> Result<&'a [u8], Error> {
+	let mut len = 0;
+	let mut overflow = false;
+
+	for &byte in card {
+		if is_separator(byte) {
+			continue;
+		}
+
+		if !byte.is_ascii_digit() {
+			return Err(Error::InvalidFormat);
+		}
+
+		match buf.get_mut(len) {
+			Some(slot) => *slot = byte,
+			None => overflow = true,
+		}
+
+		len += 1;
+	}
+
+	if overflow {
+		return Err(Error::InvalidLength);
+	}
+
+	Ok(&buf[..len])
+}
+
+/// The sizes of the groups a number of `len` digits is printed in, as in
+/// `3782 822463 10005` for American Express.
+fn canonical_groups(issuer: Issuer, len: usize) -> &'static [usize] {
+	match (issuer, len) {
+		(Issuer::AmericanExpress, 15) => &[4, 6, 5],
+		(Issuer::DinersClub, 14) => &[4, 6, 4],
+		(Issuer::Uatp, 15) => &[4, 5, 6],
+		_ => &[4; 4],
+	}
+}
+
+impl CreditCard {
+	/// Parse a credit card number from a string, ignoring spaces and
+	/// hyphens, and write its digits without them into `buf`.
+	///
+	/// Separators are always ignored here, whether or not the `separators`
+	/// feature is enabled. Returns [`Error::BufferTooSmall`] if `buf` cannot
+	/// hold the digits.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let mut buf = [0; 19];
+	/// let (card, digits) = CreditCard::parse_str_normalized("4111-1111-1111-1111", &mut buf).unwrap();
+	///
+	/// assert_eq!(card.pan(), 4111111111111111);
+	/// assert_eq!(digits, "4111111111111111");
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_normalized<'a>(
+		card: &str,
+		buf: &'a mut [u8],
+	) -> Result<(CreditCard, &'a str), Error> {
+		let mut digits = [0; 19];
+		let digits = normalize(card.as_bytes(), &mut digits)?;
+		let card = CreditCard::from_bytes(digits)?;
+
+		let buf = buf.get_mut(..digits.len()).ok_or(Error::BufferTooSmall)?;
+
+		buf.copy_from_slice(digits);
+
+		// only ascii digits were written
+		Ok((card, core::str::from_utf8(buf).unwrap()))
+	}
+
+	/// Parse a credit card number from a string, allowing separators only
+	/// between the issuer's usual groups of digits.
+	///
+	/// Numbers are grouped in fours, except for 15-digit American Express
+	/// and UATP and 14-digit Diners Club numbers, which are grouped as
+	/// `4-6-5`, `4-5-6` and `4-6-4`. Each separator must be a single space or
+	/// hyphen, though any of them may be left out. Returns
+	/// [`Error::InvalidFormat`] for a separator anywhere else.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Error};
+	///
+	/// assert!(CreditCard::parse_str_canonical_spacing("4111-1111-1111-1111").is_ok());
+	/// assert_eq!(
+	///     CreditCard::parse_str_canonical_spacing("41-111111111111-11"),
+	///     Err(Error::InvalidFormat)
+	/// );
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_canonical_spacing(card: &str) -> Result<CreditCard, Error> {
+		let mut digits = [0; 19];
+		let digits = normalize(card.as_bytes(), &mut digits)?;
+		let parsed = CreditCard::from_bytes(digits)?;
+
+		let mut boundaries = canonical_groups(parsed.issuer, digits.len())
+			.iter()
+			.scan(0, |end, &size| {
+				*end += size;
+				Some(*end)
+			})
+			.filter(|&end| end < digits.len());
+
+		let mut count = 0;
+		let mut previous = None;
+
+		for &byte in card.as_bytes() {
+			if !is_separator(byte) {
+				count += 1;
+				continue;
+			}
+
+			// boundaries are increasing, so skipped ones can't come back
+			if previous == Some(count) || !boundaries.any(|end| end == count) {
+				return Err(Error::InvalidFormat);
+			}
+
+			previous = Some(count);
+		}
+
+		Ok(parsed)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_normalize() {
+		let mut buf = [0; 19];
+
+		assert_eq!(
+			normalize(b"4111 1111-1111  1111", &mut buf),
+			Ok(&b"4111111111111111"[..])
+		);
+		assert_eq!(
+			normalize(b"411111111111111 1", &mut buf),
+			Ok(&b"4111111111111111"[..])
+		);
+		assert_eq!(normalize(b"", &mut buf), Ok(&b""[..]));
+		assert_eq!(normalize(b"4111_1111", &mut buf), Err(Error::InvalidFormat));
+		assert_eq!(
+			normalize(b"4111 1111 1111 1111 1111", &mut buf),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			normalize(b"4111 1111 1111 1111 1111 x", &mut buf),
+			Err(Error::InvalidFormat)
+		);
+	}
+
+	#[test]
+	fn test_parse_str_canonical_spacing() {
+		for card in [
+			"378282246310005",
+			"3782 822463 10005",
+			"3782-822463-10005",
+			"3782 82246310005",
+		] {
+			assert_eq!(
+				CreditCard::parse_str_canonical_spacing(card).unwrap().pan(),
+				378282246310005,
+				"{card}"
+			);
+		}
+
+		for card in [
+			"3782 8224 6310 005",
+			"378 2822463 10005",
+			"3782  822463 10005",
+			" 3782 822463 10005",
+			"3782 822463 10005 ",
+			"3782 822463-1000-5",
+		] {
+			assert_eq!(
+				CreditCard::parse_str_canonical_spacing(card),
+				Err(Error::InvalidFormat),
+				"{card}"
+			);
+		}
+
+		assert!(CreditCard::parse_str_canonical_spacing("4111 1111 1111 1111").is_ok());
+		assert!(CreditCard::parse_str_canonical_spacing("4222 2222 2222 2").is_ok());
+		assert!(CreditCard::parse_str_canonical_spacing("3056 930902 5904").is_ok());
+		assert_eq!(
+			CreditCard::parse_str_canonical_spacing("41-111111111111-11"),
+			Err(Error::InvalidFormat)
+		);
+		assert_eq!(
+			CreditCard::parse_str_canonical_spacing("4111 1111 1111 1112"),
+			Err(Error::InvalidLuhn)
+		);
+	}
+
+	#[test]
+	fn test_parse_empty() {
+		for card in ["", "----", "   ", " - "] {
+			assert_eq!(
+				CreditCard::parse_str_normalized(card, &mut [0; 19]),
+				Err(Error::Empty),
+				"{card:?}"
+			);
+			assert_eq!(
+				CreditCard::parse_str_canonical_spacing(card),
+				Err(Error::Empty),
+				"{card:?}"
+			);
+
+			#[cfg(feature = "separators")]
+			assert_eq!(card.parse::<CreditCard>(), Err(Error::Empty), "{card:?}");
+		}
+	}
+
+	#[test]
+	fn test_parse_str_normalized() {
+		let mut buf = [0; 19];
+		let (card, digits) = CreditCard::parse_str_normalized("4111-1111-1111-1111", &mut buf).unwrap();
+
+		assert_eq!(card.pan(), 4111111111111111);
+		assert_eq!(digits, "4111111111111111");
+
+		let (card, digits) = CreditCard::parse_str_normalized("3782 822463 10005", &mut buf).unwrap();
+
+		assert_eq!(card.issuer(), crate::Issuer::AmericanExpress);
+		assert_eq!(digits, "378282246310005");
+
+		assert_eq!(
+			CreditCard::parse_str_normalized("4111-1111-1111-1112", &mut buf),
+			Err(Error::InvalidLuhn)
+		);
+		assert_eq!(
+			CreditCard::parse_str_normalized("4111-1111-1111-1111", &mut [0; 15]),
+			Err(Error::BufferTooSmall)
+		);
+	}
+}
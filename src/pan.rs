@@ -0,0 +1,168 @@
+use core::fmt::{self, Write};
+
+use crate::CreditCard;
+
+/// A validated primary account number.
+///
+/// Distinct from a plain integer so it cannot be mixed up with one, and
+/// only obtainable from a [`CreditCard`]. Both [`Display`](fmt::Display) and
+/// [`Debug`](fmt::Debug) mask all but the last four digits.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{CreditCard, Pan};
+///
+/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+/// let pan = Pan::from(card);
+///
+/// assert_eq!(pan.get(), 4111111111111111);
+/// assert_eq!(pan.to_string(), "************1111");
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Pan(pub(crate) u64);
+
+impl Pan {
+	/// The number as an integer.
+	#[must_use]
+	pub fn get(self) -> u64 {
+		self.0
+	}
+}
+
+impl Pan {
+	fn write_masked<W: Write + ?Sized>(self, w: &mut W) -> fmt::Result {
+		let length = self.0.ilog10() + 1;
+
+		for _ in 4..length {
+			w.write_char('*')?;
+		}
+
+		write!(w, "{:04}", self.0 % 10000)
+	}
+}
+
+impl fmt::Display for Pan {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.write_masked(f)
+	}
+}
+
+impl fmt::Debug for Pan {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Pan").field(&format_args!("{self}")).finish()
+	}
+}
+
+/// Masks all but the last four digits of the number, as for [`Pan`].
+#[cfg(feature = "redact-debug")]
+impl fmt::Debug for CreditCard {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CreditCard")
+			.field("pan", &format_args!("{}", self.pan_typed()))
+			.field("issuer", &self.issuer)
+			.finish()
+	}
+}
+
+impl From<CreditCard> for Pan {
+	fn from(card: CreditCard) -> Self {
+		Self(card.pan)
+	}
+}
+
+impl CreditCard {
+	/// The credit card number as a [`Pan`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert_eq!(card.pan_typed().get(), card.pan());
+	/// ```
+	#[must_use]
+	pub fn pan_typed(&self) -> Pan {
+		Pan(self.pan)
+	}
+
+	/// Writes the card number into `w` with all but the last four digits
+	/// replaced by `*`, as the [`Pan`] is displayed.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	/// let mut masked = String::new();
+	///
+	/// card.write_masked(&mut masked).unwrap();
+	///
+	/// assert_eq!(masked, "************1111");
+	/// ```
+	pub fn write_masked<W: Write>(&self, w: &mut W) -> fmt::Result {
+		self.pan_typed().write_masked(w)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::{format, string::ToString};
+
+	use super::*;
+
+	#[test]
+	fn test_pan() {
+		let card = "378282246310005".parse::<CreditCard>().unwrap();
+		let pan = Pan::from(card);
+
+		assert_eq!(pan, card.pan_typed());
+		assert_eq!(pan.get(), 378282246310005);
+		assert_eq!(pan.to_string(), "***********0005");
+		assert_eq!(format!("{pan:?}"), "Pan(***********0005)");
+	}
+
+	#[cfg(feature = "redact-debug")]
+	#[test]
+	fn test_debug_redacted() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(
+			format!("{card:?}"),
+			"CreditCard { pan: ************1111, issuer: Visa }"
+		);
+	}
+
+	#[cfg(not(feature = "redact-debug"))]
+	#[test]
+	fn test_debug_full() {
+		let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+		assert_eq!(
+			format!("{card:?}"),
+			"CreditCard { pan: 4111111111111111, issuer: Visa }"
+		);
+	}
+
+	#[cfg(feature = "heapless")]
+	#[test]
+	fn test_write_masked() {
+		let card = "4222222222222".parse::<CreditCard>().unwrap();
+		let mut masked = heapless::String::<19>::new();
+
+		card.write_masked(&mut masked).unwrap();
+
+		assert_eq!(masked, "*********2222");
+		assert_eq!(masked, card.pan_typed().to_string().as_str());
+
+		// too small to hold the whole number
+		assert!(
+			card
+				.write_masked(&mut heapless::String::<12>::new())
+				.is_err()
+		);
+	}
+}
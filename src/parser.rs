@@ -0,0 +1,224 @@
+use crate::{
+	CreditCard, Error, Issuer,
+	iin::{self, Prefix},
+};
+
+/// An incremental credit card parser, fed one digit at a time.
+///
+/// Useful for terminals and forms that receive digits as they are typed,
+/// since the issuer can be reported without re-parsing the whole number on
+/// every keystroke.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{Issuer, Parser, PushResult};
+///
+/// let mut parser = Parser::new();
+///
+/// for digit in [4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1] {
+///     parser.push(digit);
+/// }
+///
+/// assert_eq!(parser.push(1), PushResult::Issuer(Issuer::Visa));
+/// assert_eq!(parser.finish().unwrap().pan(), 4111111111111111);
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+pub struct Parser {
+	digits: [u8; 19],
+	len: usize,
+}
+
+/// The state of a [`Parser`] after a digit is pushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushResult {
+	/// The digits so far can only belong to this issuer.
+	Issuer(Issuer),
+	/// The digits so far could belong to more than one issuer.
+	Ambiguous,
+	/// The digits so far cannot form a valid card number.
+	Invalid,
+}
+
+/// Leaves out the digits pushed so far, which may be most of a card number.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for Parser {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("Parser")
+			.field("len", &self.len)
+			.finish_non_exhaustive()
+	}
+}
+
+impl Parser {
+	/// Creates an empty parser.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Pushes a digit in the range 0..=9.
+	///
+	/// Digits outside that range, or past the 19-digit maximum, are not
+	/// recorded and return [`PushResult::Invalid`].
+	pub fn push(&mut self, digit: u8) -> PushResult {
+		if digit > 9 || self.len == self.digits.len() {
+			return PushResult::Invalid;
+		}
+
+		self.digits[self.len] = b'0' + digit;
+		self.len += 1;
+
+		self.status()
+	}
+
+	/// Runs the final length and Luhn checks on the digits pushed so far.
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn finish(self) -> Result<CreditCard, Error> {
+		CreditCard::from_bytes(&self.digits[..self.len])
+	}
+
+	fn status(&self) -> PushResult {
+		match iin::classify_prefix(&self.digits[..self.len]) {
+			Ok(Prefix::Unique(issuer)) => PushResult::Issuer(issuer),
+			Ok(Prefix::Ambiguous) => PushResult::Ambiguous,
+			Ok(Prefix::Unknown) | Err(_) => PushResult::Invalid,
+		}
+	}
+}
+
+impl CreditCard {
+	/// Parse a possibly incomplete credit card number, returning its issuer
+	/// whenever the digits so far identify one.
+	///
+	/// The card is only returned once it also passes the length and Luhn
+	/// checks. Returns [`Error::UnknownType`] if the digits could still belong
+	/// to more than one issuer, or to none, and [`Error::InvalidLength`] if no
+	/// valid length for the issuer remains.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer};
+	///
+	/// assert_eq!(CreditCard::parse_partial("3782"), Ok((None, Issuer::AmericanExpress)));
+	///
+	/// let (card, issuer) = CreditCard::parse_partial("378282246310005").unwrap();
+	///
+	/// assert_eq!(issuer, Issuer::AmericanExpress);
+	/// assert!(card.is_some());
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_partial(card: &str) -> Result<(Option<CreditCard>, Issuer), Error> {
+		let bytes = card.as_bytes();
+
+		match CreditCard::parse_str(card) {
+			Ok(card) => return Ok((Some(card), card.issuer)),
+			Err(error @ (Error::InvalidFormat | Error::Empty)) => return Err(error),
+			Err(_) if bytes.len() > 19 => return Err(Error::InvalidLength),
+			Err(_) => {}
+		}
+
+		match iin::classify_prefix(bytes)? {
+			Prefix::Unique(issuer) => Ok((None, issuer)),
+			Prefix::Ambiguous | Prefix::Unknown => Err(Error::UnknownType),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_push_visa() {
+		let mut parser = Parser::new();
+
+		assert_eq!(parser.push(4), PushResult::Ambiguous);
+		// could still be a 417500 Visa Electron
+		assert_eq!(parser.push(1), PushResult::Ambiguous);
+
+		for digit in [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1] {
+			assert_eq!(parser.push(digit), PushResult::Issuer(Issuer::Visa));
+		}
+
+		assert_eq!(parser.clone().finish(), Err(Error::InvalidLength));
+		assert_eq!(parser.push(1), PushResult::Issuer(Issuer::Visa));
+
+		let card = parser.finish().unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+		assert_eq!(card.pan(), 4111111111111111);
+	}
+
+	#[test]
+	fn test_push_invalid_prefix() {
+		let mut parser = Parser::new();
+
+		assert_eq!(parser.push(7), PushResult::Invalid);
+		assert_eq!(parser.push(1), PushResult::Invalid);
+		assert_eq!(parser.push(10), PushResult::Invalid);
+		assert_eq!(parser.finish(), Err(Error::UnknownType));
+
+		let mut parser = Parser::new();
+
+		assert_eq!(parser.push(0), PushResult::Invalid);
+		assert_eq!(Parser::new().finish(), Err(Error::Empty));
+	}
+
+	#[test]
+	fn test_push_too_long() {
+		let mut parser = Parser::new();
+
+		assert_eq!(parser.push(3), PushResult::Ambiguous);
+
+		for digit in [7, 8, 2, 8, 2, 2, 4, 6, 3, 1, 0, 0, 0, 5] {
+			assert_eq!(
+				parser.push(digit),
+				PushResult::Issuer(Issuer::AmericanExpress)
+			);
+		}
+
+		assert_eq!(parser.push(0), PushResult::Invalid);
+	}
+
+	#[cfg(feature = "redact-debug")]
+	#[test]
+	fn test_debug_redacted() {
+		let mut parser = Parser::new();
+
+		for digit in [4, 1, 1, 1, 1] {
+			parser.push(digit);
+		}
+
+		assert_eq!(std::format!("{parser:?}"), "Parser { len: 5, .. }");
+	}
+
+	#[test]
+	fn test_parse_partial() {
+		let (card, issuer) = CreditCard::parse_partial("4111111111111111").unwrap();
+
+		assert_eq!(issuer, Issuer::Visa);
+		assert_eq!(card.unwrap().pan(), 4111111111111111);
+
+		assert_eq!(CreditCard::parse_partial("411"), Ok((None, Issuer::Visa)));
+		assert_eq!(
+			CreditCard::parse_partial("4111111111111112"),
+			Ok((None, Issuer::Visa))
+		);
+		assert_eq!(CreditCard::parse_partial("41"), Err(Error::UnknownType));
+		assert_eq!(CreditCard::parse_partial("7"), Err(Error::UnknownType));
+		assert_eq!(CreditCard::parse_partial("0411"), Err(Error::UnknownType));
+		assert_eq!(CreditCard::parse_partial("41a"), Err(Error::InvalidFormat));
+		assert_eq!(CreditCard::parse_partial(""), Err(Error::Empty));
+		assert_eq!(
+			CreditCard::parse_partial("41111111111111111111"),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			CreditCard::parse_partial("37828224631000512"),
+			Err(Error::InvalidLength)
+		);
+	}
+}
@@ -0,0 +1,88 @@
+use crate::{CreditCard, Issuer};
+
+/// The commonly used fields of a credit card, computed together.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{CardParts, CreditCard, Issuer};
+///
+/// let card = "4012888888881881".parse::<CreditCard>().unwrap();
+/// let CardParts { issuer, bin6, check_digit, .. } = card.parts();
+///
+/// assert_eq!(issuer, Issuer::Visa);
+/// assert_eq!(bin6, 401288);
+/// assert_eq!(check_digit, 1);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+pub struct CardParts {
+	/// The kind of credit card.
+	pub issuer: Issuer,
+	/// The first six digits of the card number.
+	pub bin6: u32,
+	/// The full card number.
+	pub pan: u64,
+	/// The last digit of the card number.
+	pub check_digit: u8,
+	/// The number of digits in the card number.
+	pub length: usize,
+}
+
+/// Masks all but the last four digits of the number, as for
+/// [`Pan`](crate::Pan).
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for CardParts {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("CardParts")
+			.field("issuer", &self.issuer)
+			.field("bin6", &self.bin6)
+			.field("pan", &format_args!("{}", crate::Pan(self.pan)))
+			.field("check_digit", &self.check_digit)
+			.field("length", &self.length)
+			.finish()
+	}
+}
+
+impl CreditCard {
+	/// Splits the card into its commonly used fields.
+	#[must_use]
+	pub fn parts(&self) -> CardParts {
+		CardParts {
+			issuer: self.issuer,
+			bin6: self.bin6(),
+			pan: self.pan,
+			check_digit: (self.pan % 10) as u8,
+			length: self.length(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parts() {
+		let card = "4222222222222".parse::<CreditCard>().unwrap();
+
+		assert_eq!(card.parts(), CardParts {
+			issuer: Issuer::Visa,
+			bin6: 422222,
+			pan: 4222222222222,
+			check_digit: 2,
+			length: 13,
+		});
+	}
+
+	#[cfg(feature = "redact-debug")]
+	#[test]
+	fn test_parts_debug_redacted() {
+		let parts = "4111111111111111".parse::<CreditCard>().unwrap().parts();
+
+		assert_eq!(
+			std::format!("{parts:?}"),
+			"CardParts { issuer: Visa, bin6: 411111, pan: ************1111, check_digit: 1, length: 16 }"
+		);
+	}
+}
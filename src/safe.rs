@@ -0,0 +1,91 @@
+use crate::{CreditCard, Issuer};
+
+/// A view of a credit card without its full number, for audit logs.
+///
+/// Serializes as `{ "issuer", "bin6", "last_four", "length" }`. Since the
+/// middle digits are discarded, a [`SafeCard`] can never be turned back into
+/// a [`CreditCard`].
+///
+/// # Example
+///
+/// ```
+/// use creditcard::CreditCard;
+///
+/// let card = "4111111111111111".parse::<CreditCard>().unwrap().safe();
+///
+/// assert_eq!(
+///     serde_json::to_string(&card).unwrap(),
+///     r#"{"issuer":"Visa","bin6":411111,"last_four":1111,"length":16}"#
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct SafeCard {
+	issuer: Issuer,
+	bin6: u32,
+	last_four: u16,
+	length: u8,
+}
+
+impl SafeCard {
+	/// The kind of credit card.
+	#[must_use]
+	pub fn issuer(&self) -> Issuer {
+		self.issuer
+	}
+
+	/// The first six digits of the credit card number.
+	#[must_use]
+	pub fn bin6(&self) -> u32 {
+		self.bin6
+	}
+
+	/// The last four digits of the credit card number.
+	#[must_use]
+	pub fn last_four(&self) -> u16 {
+		self.last_four
+	}
+
+	/// The number of digits in the credit card number.
+	#[must_use]
+	pub fn length(&self) -> u8 {
+		self.length
+	}
+}
+
+impl CreditCard {
+	/// Discards everything but the issuer, first six and last four digits,
+	/// and length.
+	#[must_use]
+	pub fn safe(&self) -> SafeCard {
+		SafeCard {
+			issuer: self.issuer,
+			bin6: self.bin6(),
+			last_four: (self.pan % 10000) as u16,
+			length: self.length() as u8,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_safe_card() {
+		let card = "378282246310005".parse::<CreditCard>().unwrap().safe();
+
+		assert_eq!(card.issuer(), Issuer::AmericanExpress);
+		assert_eq!(card.bin6(), 378282);
+		assert_eq!(card.last_four(), 5);
+		assert_eq!(card.length(), 15);
+
+		let json = serde_json::to_string(&card).unwrap();
+
+		assert_eq!(
+			json,
+			r#"{"issuer":"AmericanExpress","bin6":378282,"last_four":5,"length":15}"#
+		);
+		assert!(!json.contains("378282246310005"));
+		assert_eq!(serde_json::from_str::<SafeCard>(&json).unwrap(), card);
+	}
+}
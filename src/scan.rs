@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use crate::{CreditCard, normalize::is_separator};
+
+/// Finds every valid card number in `text`, such as for a data-loss
+/// prevention scanner.
+///
+/// Digits may be grouped by single spaces or hyphens. A card number must
+/// start at the beginning of a group or right after another card number,
+/// and the longest valid one there is taken, so found cards never overlap.
+///
+/// # Example
+///
+/// ```
+/// let cards = creditcard::scan_text("paid with 4111 1111 1111 1111, refunded to 378282246310005.");
+///
+/// assert_eq!(cards.len(), 2);
+/// assert_eq!(cards[0].pan(), 4111111111111111);
+/// assert_eq!(cards[1].pan(), 378282246310005);
+/// ```
+#[must_use]
+pub fn scan_text(text: &str) -> Vec<CreditCard> {
+	let bytes = text.as_bytes();
+	let mut cards = Vec::new();
+	let mut digits = Vec::new();
+	// where each group of digits starts within `digits`
+	let mut groups = Vec::new();
+
+	for (i, &byte) in bytes.iter().enumerate() {
+		if byte.is_ascii_digit() {
+			if i == 0 || !bytes[i - 1].is_ascii_digit() {
+				groups.push(digits.len());
+			}
+
+			digits.push(byte);
+			continue;
+		}
+
+		// a single separator between two digits continues the run
+		let between_digits =
+			i > 0 && bytes[i - 1].is_ascii_digit() && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+
+		if !(is_separator(byte) && between_digits) {
+			scan_digits(&digits, &groups, &mut cards);
+			digits.clear();
+			groups.clear();
+		}
+	}
+
+	scan_digits(&digits, &groups, &mut cards);
+
+	cards
+}
+
+fn scan_digits(digits: &[u8], groups: &[usize], cards: &mut Vec<CreditCard>) {
+	let Some(&(mut start)) = groups.first() else {
+		return;
+	};
+
+	loop {
+		let rest = &digits[start..];
+		let found = (12..=rest.len().min(19))
+			.rev()
+			.find_map(|len| Some((CreditCard::from_bytes(&rest[..len]).ok()?, len)));
+
+		if let Some((card, len)) = found {
+			cards.push(card);
+			start += len;
+		} else if let Some(&group) = groups.iter().find(|&&group| group > start) {
+			start = group;
+		} else {
+			break;
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn pans(text: &str) -> Vec<u64> {
+		scan_text(text).iter().map(CreditCard::pan).collect()
+	}
+
+	#[test]
+	fn test_scan_text() {
+		assert_eq!(
+			pans("card 4111-1111-1111-1111 and 5555 5555 5555 4444 on file"),
+			[4111111111111111, 5555555555554444]
+		);
+		assert_eq!(pans("4111111111111111,378282246310005"), [
+			4111111111111111,
+			378282246310005
+		]);
+		// adjacent without anything between them
+		assert_eq!(pans("order 12345 4111111111111111378282246310005"), [
+			4111111111111111,
+			378282246310005
+		]);
+		// 345411111111111 would be a valid American Express number
+		assert_eq!(pans("ref 12345 4111111111111111"), [4111111111111111]);
+		assert_eq!(pans("4111 1111  1111 1111"), [] as [u64; 0]);
+		assert_eq!(pans("4111111111111112 and 1234"), [] as [u64; 0]);
+		assert_eq!(pans(""), [] as [u64; 0]);
+	}
+}
@@ -0,0 +1,136 @@
+use crate::{CreditCard, Error, Issuer, iin::ExtendedIinRange};
+
+/// Never matches, since `low` is above `high`.
+const EMPTY: ExtendedIinRange = ExtendedIinRange {
+	digits: 8,
+	low: 1,
+	high: 0,
+	issuer: Issuer::Visa,
+};
+
+/// The built-in IIN ranges, patched with up to `N` overrides.
+///
+/// Useful when a network reassigns a range before this crate is updated.
+///
+/// # Example
+///
+/// ```
+/// use creditcard::{CreditCard, Issuer, IssuerTable};
+///
+/// let table: IssuerTable = IssuerTable::new().with_override(41111111, 41111111, Issuer::UnionPay);
+/// let card = CreditCard::parse_str_with("4111111111111111", &table).unwrap();
+///
+/// assert_eq!(card.issuer(), Issuer::UnionPay);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IssuerTable<const N: usize = 8> {
+	overrides: [ExtendedIinRange; N],
+	len: usize,
+}
+
+impl<const N: usize> IssuerTable<N> {
+	/// Creates a table with no overrides.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			overrides: [EMPTY; N],
+			len: 0,
+		}
+	}
+
+	/// Classifies the 8-digit IINs from `low` to `high` inclusive as
+	/// `issuer`.
+	///
+	/// Overrides take precedence over the built-in ranges, and earlier
+	/// overrides take precedence over later ones.
+	///
+	/// # Panics
+	///
+	/// Panics if the table already holds `N` overrides.
+	#[must_use]
+	pub const fn with_override(mut self, low: u32, high: u32, issuer: Issuer) -> Self {
+		assert!(self.len < N, "too many overrides");
+
+		self.overrides[self.len] = ExtendedIinRange {
+			digits: 8,
+			low: low as u64,
+			high: high as u64,
+			issuer,
+		};
+		self.len += 1;
+
+		self
+	}
+
+	fn overrides(&self) -> &[ExtendedIinRange] {
+		&self.overrides[..self.len]
+	}
+}
+
+impl<const N: usize> Default for IssuerTable<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl CreditCard {
+	/// Parse a credit card number from a string, classifying it with
+	/// `table` instead of the built-in IIN ranges alone.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::{CreditCard, Issuer, IssuerTable};
+	///
+	/// let table: IssuerTable = IssuerTable::new();
+	/// let card = CreditCard::parse_str_with("4111111111111111", &table).unwrap();
+	///
+	/// assert_eq!(card.issuer(), Issuer::Visa);
+	/// ```
+	#[must_use = "the card is only validated if the result is checked"]
+	pub fn parse_str_with<const N: usize>(
+		card: &str,
+		table: &IssuerTable<N>,
+	) -> Result<CreditCard, Error> {
+		Self::from_bytes_with(card.as_bytes(), table.overrides())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_override() {
+		let table = IssuerTable::<2>::new()
+			.with_override(41111111, 41111111, Issuer::UnionPay)
+			.with_override(41000000, 41999999, Issuer::Mastercard);
+
+		let card = CreditCard::parse_str_with("4111111111111111", &table).unwrap();
+
+		assert_eq!(card.issuer(), Issuer::UnionPay);
+		assert_eq!(card.pan(), 4111111111111111);
+
+		let card = CreditCard::parse_str_with("4012888888881881", &table).unwrap();
+
+		assert_eq!(card.issuer(), Issuer::Visa);
+
+		// 13 digits is valid for Visa but not for the overriding Mastercard
+		assert_eq!(
+			CreditCard::parse_str_with("4122222222224", &table),
+			Err(Error::InvalidLength)
+		);
+		assert_eq!(
+			CreditCard::parse_str("4111111111111111").unwrap().issuer(),
+			Issuer::Visa
+		);
+	}
+
+	#[test]
+	#[should_panic = "too many overrides"]
+	fn test_override_full() {
+		let _ = IssuerTable::<1>::new()
+			.with_override(41111111, 41111111, Issuer::UnionPay)
+			.with_override(41000000, 41999999, Issuer::Mastercard);
+	}
+}
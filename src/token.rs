@@ -0,0 +1,148 @@
+use crate::{CreditCard, iin, luhn, pan_to_ascii};
+
+/// BINs reported to be reserved for network tokens (DPANs) rather than
+/// physical cards, as inclusive ranges of 8-digit IINs.
+///
+/// This is a small seed table and is far from exhaustive.
+#[allow(clippy::inconsistent_digit_grouping)]
+const TOKEN_RANGES: &[(u32, u32)] = &[
+	// Visa Token Service
+	(489537_00, 489537_99),
+	// Mastercard Digital Enablement Service
+	(520473_00, 520473_99),
+];
+
+impl CreditCard {
+	/// Replaces the middle digits of the card number with a keyed
+	/// permutation of themselves, producing a valid look-alike card.
+	///
+	/// The first 8 digits (the IIN) and the length are kept, so the token
+	/// has the same issuer, and the check digit is recomputed so the token
+	/// passes the Luhn check. The same card and key always produce the same
+	/// token.
+	///
+	/// This is meant for test data, not as a replacement for vaulted
+	/// tokenization: anyone holding the key can reverse it.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	/// let token = card.tokenize(42);
+	///
+	/// assert_eq!(token.issuer(), card.issuer());
+	/// assert_eq!(token.pan() / 100000000, card.pan() / 100000000);
+	/// ```
+	#[must_use]
+	pub fn tokenize(&self, key: u64) -> CreditCard {
+		// everything between the IIN and the check digit
+		let middle_len = self.length() as u32 - 9;
+		let modulus = 10u64.pow(middle_len);
+
+		let prefix = self.pan / 10u64.pow(middle_len + 1);
+		let middle = self.pan / 10 % modulus;
+
+		// an affine map is a permutation when the multiplier is coprime to the
+		// modulus, which for powers of 10 means not divisible by 2 or 5
+		let mut multiplier = (mix(key) % modulus) | 1;
+
+		if multiplier.is_multiple_of(5) {
+			multiplier += 2;
+		}
+
+		let offset = mix(!key) % modulus;
+		let middle =
+			(u128::from(middle) * u128::from(multiplier) + u128::from(offset)) % u128::from(modulus);
+
+		let payload = prefix * modulus + middle as u64;
+
+		let mut buf = [0; 20];
+		let check_digit = luhn::check_digit(pan_to_ascii(payload, &mut buf));
+
+		CreditCard {
+			pan: payload * 10 + u64::from(check_digit),
+			issuer: self.issuer,
+		}
+	}
+}
+
+impl CreditCard {
+	/// Whether the card number falls in a BIN known to be used for network
+	/// tokens, such as those provisioned to mobile wallets, instead of a
+	/// physical card.
+	///
+	/// Only a small table of token BINs is known, so `false` does not mean
+	/// the number is not a token.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use creditcard::CreditCard;
+	///
+	/// let card = "4111111111111111".parse::<CreditCard>().unwrap();
+	///
+	/// assert!(!card.is_likely_token());
+	/// ```
+	#[must_use]
+	pub fn is_likely_token(&self) -> bool {
+		let iin = iin::iin8(self.pan, self.length());
+
+		TOKEN_RANGES
+			.iter()
+			.any(|&(low, high)| low <= iin && iin <= high)
+	}
+}
+
+/// The splitmix64 finalizer.
+fn mix(key: u64) -> u64 {
+	let key = (key ^ (key >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+	let key = (key ^ (key >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+	key ^ (key >> 31)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_tokenize() {
+		for card in ["4111111111111111", "378282246310005", "6759649826438453"] {
+			let card = card.parse::<CreditCard>().unwrap();
+			let token = card.tokenize(42);
+
+			assert_eq!(token.issuer(), card.issuer());
+			assert_eq!(token.length(), card.length());
+			assert_ne!(token, card);
+			assert_eq!(token, card.tokenize(42));
+
+			let mut buf = [0; 20];
+
+			assert!(luhn::is_valid(pan_to_ascii(token.pan(), &mut buf)));
+			assert_eq!(CreditCard::reclassify(token.pan()), Ok(card.issuer()));
+		}
+	}
+
+	#[test]
+	fn test_is_likely_token() {
+		for card in ["4895370000000007", "5204730000000005"] {
+			assert!(card.parse::<CreditCard>().unwrap().is_likely_token());
+		}
+
+		for card in ["4111111111111111", "5555555555554444", "4895380000000006"] {
+			assert!(!card.parse::<CreditCard>().unwrap().is_likely_token());
+		}
+	}
+
+	#[test]
+	fn test_tokenize_short() {
+		let card = "4222222222222".parse::<CreditCard>().unwrap();
+		let token = card.tokenize(7);
+
+		assert_eq!(token.length(), 13);
+		assert_eq!(token.pan() / 100000, card.pan() / 100000);
+		assert_eq!(CreditCard::reclassify(token.pan()), Ok(card.issuer()));
+	}
+}
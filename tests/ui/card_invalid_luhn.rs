@@ -0,0 +1,5 @@
+use creditcard::card;
+
+fn main() {
+	let _ = card!("4111111111111112");
+}
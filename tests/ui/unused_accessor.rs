@@ -0,0 +1,10 @@
+#![deny(unused_must_use)]
+
+use creditcard::CreditCard;
+
+fn main() {
+	let card = "4111111111111111".parse::<CreditCard>().unwrap();
+
+	card.issuer();
+	creditcard::is_valid("4111111111111111");
+}
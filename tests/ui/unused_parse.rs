@@ -0,0 +1,7 @@
+#![deny(unused_must_use)]
+
+use creditcard::CreditCard;
+
+fn main() {
+	CreditCard::parse_str("4111111111111111");
+}